@@ -1,59 +1,283 @@
-use crate::hat::Person;
+use crate::avatar::Photos;
+use crate::hat::{Pair, Person};
+use crate::share::{self, Setup};
+use crate::sound::Sound;
+use crate::{fonts, i18n};
 
-use self::page::{Page, PeoplePage, WheelPage};
+use self::confirm::Confirm;
+use self::page::{Page, PeoplePage, SettingsPage, WheelPage};
+use self::settings::Settings;
+use self::shortcuts::Action;
 
+mod confirm;
 mod page;
+mod settings;
+mod shortcuts;
 
 /// We derive Deserialize/Serialize so we can persist app state on shutdown.
 #[derive(serde::Deserialize, serde::Serialize, Default)]
 #[serde(default)] // if we add new fields, give them default values when deserializing old state
 pub struct GiftExchangeApp {
     people: Vec<Person>,
+    fixed_pairs: Vec<Pair>,
+    /// The photos people have as pictures, which they refer to by id.
+    photos: Photos,
     page: Page,
     people_page: PeoplePage,
     wheel_page: WheelPage,
+    settings_page: SettingsPage,
+    settings: Settings,
+    #[serde(skip)]
+    sound: Sound,
+    /// Whether the window was last made fullscreen for presenting the wheel.
+    #[serde(skip)]
+    fullscreen: bool,
+    #[serde(skip)]
+    cheat_sheet_open: bool,
+    /// A setup from a shared link, waiting for the go-ahead to replace the current one.
+    #[serde(skip)]
+    open_shared: Option<Confirm<Setup>>,
+    /// Why a shared link couldn't be opened.
+    #[serde(skip)]
+    share_error: Option<String>,
 }
 
 impl GiftExchangeApp {
     /// Called once before the first frame.
     pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
+        // Load previous app state (if any).
+        // Note that you must enable the `persistence` feature for this to work.
+        let mut app: Self = cc
+            .storage
+            .and_then(|storage| eframe::get_value(storage, eframe::APP_KEY))
+            .unwrap_or_default();
+
         // This is also where you can customize the look and feel of egui using
         // `cc.egui_ctx.set_visuals` and `cc.egui_ctx.set_fonts`.
-        cc.egui_ctx.set_visuals(egui::Visuals::light());
+        let system_theme = cc.integration_info.system_theme;
+        cc.egui_ctx
+            .set_visuals(app.settings.theme.visuals(system_theme));
+        fonts::install_fallback_fonts(&cc.egui_ctx);
+        app.adopt_photos();
 
-        // Load previous app state (if any).
-        // Note that you must enable the `persistence` feature for this to work.
-        if let Some(storage) = cc.storage {
-            return eframe::get_value(storage, eframe::APP_KEY).unwrap_or_default();
+        #[cfg(target_arch = "wasm32")]
+        let app = app.with_location(&cc.integration_info.web_info.location);
+
+        app
+    }
+
+    /// Remembers the web app's address for making links, and opens the setup in the address's
+    /// fragment if there is one.
+    #[cfg(target_arch = "wasm32")]
+    fn with_location(mut self, location: &eframe::Location) -> Self {
+        // despite its docs, eframe's `url` is the whole `href`, fragment and all
+        let url = location.url.split('#').next().unwrap_or_default();
+        self.people_page.web_address = url.to_owned();
+        if let Some(shared) = share::from_fragment(&location.hash) {
+            self.open_link(shared);
+            // so reloading the page doesn't offer the same setup again
+            forget_fragment();
         }
+        self
+    }
+
+    /// Moves photos out of people in state saved before they were kept in [`Photos`].
+    fn adopt_photos(&mut self) {
+        let people = self.people.iter_mut().chain(
+            self.fixed_pairs
+                .iter_mut()
+                .flat_map(|pair| [&mut pair.giver, &mut pair.receiver]),
+        );
+        for avatar in people.filter_map(|person| person.avatar.as_mut()) {
+            self.photos.adopt(avatar);
+        }
+    }
 
-        Default::default()
+    /// Opens the setup from a shared link, first asking before replacing people already entered.
+    fn open_link(&mut self, shared: Result<Setup, String>) {
+        let setup = match shared {
+            Ok(setup) => setup,
+            Err(msg) => {
+                self.share_error = Some(msg);
+                return;
+            }
+        };
+        if self.people.is_empty() && self.fixed_pairs.is_empty() {
+            self.use_setup(setup);
+            return;
+        }
+        self.open_shared = Some(
+            Confirm::new(setup, tr!("share-open-title"), tr!("share-open"))
+                .loses(tr!(
+                    "share-open-replaces",
+                    people = self.people.len(),
+                    pairs = self.fixed_pairs.len()
+                ))
+                .export(page::people_text(&self.people, &self.fixed_pairs)),
+        );
+    }
+
+    fn use_setup(&mut self, setup: Setup) {
+        self.people = setup.people;
+        self.fixed_pairs = setup.fixed_pairs;
+        self.page = Page::People;
+    }
+
+    fn run_shortcut(&mut self, action: Action, time: f32) {
+        match action {
+            Action::ShowPeople => self.page = Page::People,
+            Action::ShowWheel => self.page = Page::Wheel,
+            Action::ShowSettings => self.page = Page::Settings,
+            Action::ShowAbout => self.page = Page::About,
+            Action::AddPerson => {
+                self.page = Page::People;
+                self.people_page.focus_name = true;
+            }
+            Action::CheatSheet => self.cheat_sheet_open = !self.cheat_sheet_open,
+            Action::Present => {
+                self.page = Page::Wheel;
+                self.wheel_page.presenting = !self.wheel_page.presenting;
+            }
+            Action::Spin | Action::NextSpin | Action::Undo | Action::Restart => {
+                if self.page == Page::Wheel {
+                    self.wheel_page.run_shortcut(
+                        action,
+                        &self.people,
+                        &self.fixed_pairs,
+                        &self.settings,
+                        time,
+                    );
+                }
+            }
+        }
     }
 }
 
 impl eframe::App for GiftExchangeApp {
     /// Called by the frame work to save state before shutdown.
     fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        let everyone = self
+            .people
+            .iter()
+            .chain(std::iter::once(&self.people_page.person))
+            .chain(
+                self.fixed_pairs
+                    .iter()
+                    .flat_map(|pair| [&pair.giver, &pair.receiver]),
+            )
+            .chain(self.wheel_page.roster());
+        self.photos
+            .retain_used(everyone.filter_map(|person| person.avatar.as_ref()));
         eframe::set_value(storage, eframe::APP_KEY, self);
     }
 
     /// Called each time the UI needs repainting, which may be many times per second.
     /// Put your widgets into a `SidePanel`, `TopPanel`, `CentralPanel`, `Window` or `Area`.
-    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
-            ui.heading("Gift Exchange Wheel");
-
-            ui.horizontal(|ui| {
-                ui.selectable_value(&mut self.page, Page::People, "People");
-                ui.selectable_value(&mut self.page, Page::Wheel, "Wheel");
-                ui.selectable_value(&mut self.page, Page::About, "About");
+    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        i18n::set_language(self.settings.language);
+        self.photos.load_textures(ctx);
+        let visuals = self.settings.theme.visuals(frame.info().system_theme);
+        if ctx.style().visuals.dark_mode != visuals.dark_mode {
+            ctx.set_visuals(visuals);
+        }
+
+        // typing into a field, picking a new shortcut or answering a confirmation takes priority
+        let confirming = self.wheel_page.confirming()
+            || self.people_page.confirming()
+            || self.open_shared.is_some();
+        if !ctx.wants_keyboard_input() && !self.settings_page.recording_shortcut() && !confirming {
+            let time = ctx.input().time as f32;
+            for action in self.settings.shortcuts.pressed(ctx) {
+                self.run_shortcut(action, time);
+            }
+        }
+
+        let presenting = self.page == Page::Wheel && self.wheel_page.presenting;
+        if presenting != self.fullscreen {
+            #[cfg(not(target_arch = "wasm32"))]
+            frame.set_fullscreen(presenting);
+            self.fullscreen = presenting;
+        }
+
+        if !presenting {
+            egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
+                ui.heading(tr!("app-title"));
+
+                i18n::horizontal(ui, |ui| {
+                    ui.selectable_value(&mut self.page, Page::People, tr!("nav-people"));
+                    ui.selectable_value(&mut self.page, Page::Wheel, tr!("nav-wheel"));
+                    ui.selectable_value(&mut self.page, Page::Settings, tr!("nav-settings"));
+                    ui.selectable_value(&mut self.page, Page::About, tr!("nav-about"));
+                    let hint = tr!(
+                        "nav-shortcuts-hint",
+                        shortcut = self.settings.shortcuts.describe(Action::CheatSheet, ctx)
+                    );
+                    if ui.button("⌨").on_hover_text(hint).clicked() {
+                        self.cheat_sheet_open = !self.cheat_sheet_open;
+                    }
+                });
+                if let Some(msg) = &self.share_error {
+                    let mut dismissed = false;
+                    i18n::horizontal(ui, |ui| {
+                        ui.colored_label(egui::Color32::RED, msg);
+                        dismissed = ui.small_button("X").clicked();
+                    });
+                    if dismissed {
+                        self.share_error = None;
+                    }
+                }
             });
-        });
+        }
 
         match self.page {
-            Page::People => page::dipslay_people(&mut self.people_page, &mut self.people, ctx),
-            Page::Wheel => self.wheel_page.display(&self.people, ctx),
+            Page::People => {
+                page::dipslay_people(
+                    &mut self.people_page,
+                    &mut self.people,
+                    &mut self.fixed_pairs,
+                    &mut self.photos,
+                    ctx,
+                );
+                if let Some(link) = self.people_page.link_to_open.take() {
+                    self.open_link(share::from_link(&link));
+                }
+            }
+            Page::Wheel => self.wheel_page.display(
+                &self.people,
+                &self.fixed_pairs,
+                &self.settings,
+                &mut self.sound,
+                ctx,
+            ),
+            Page::Settings => page::display_settings(
+                &mut self.settings_page,
+                &mut self.settings,
+                self.sound.unavailable_reason(),
+                ctx,
+            ),
             Page::About => page::display_about(ctx),
         }
+
+        shortcuts::cheat_sheet(ctx, &mut self.cheat_sheet_open, &self.settings.shortcuts);
+        if let Some(setup) = confirm::show(ctx, &mut self.open_shared) {
+            self.use_setup(setup);
+        }
+    }
+}
+
+/// Takes the fragment off the address without reloading, leaving the rest as it was.
+#[cfg(target_arch = "wasm32")]
+fn forget_fragment() {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let location = window.location();
+    // built from the still percent-encoded parts, as eframe's `url` has been decoded
+    let (Ok(path), Ok(query)) = (location.pathname(), location.search()) else {
+        return;
+    };
+    if let Ok(history) = window.history() {
+        let url = format!("{path}{query}");
+        let _ = history.replace_state_with_url(&wasm_bindgen::JsValue::NULL, "", Some(&url));
     }
 }
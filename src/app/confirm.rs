@@ -0,0 +1,108 @@
+//! Asking before doing something that throws work away.
+
+use egui::{Align2, Color32, Id, LayerId, Order, RichText, Sense, Vec2};
+
+/// An action held back until the user says to go ahead, with a list of what it will lose.
+pub(crate) struct Confirm<T> {
+    action: T,
+    title: String,
+    losses: Vec<String>,
+    /// Text the user can copy out first, so nothing is really lost.
+    export: Option<String>,
+    confirm_label: String,
+}
+
+impl<T> Confirm<T> {
+    pub(crate) fn new(
+        action: T,
+        title: impl Into<String>,
+        confirm_label: impl Into<String>,
+    ) -> Self {
+        Self {
+            action,
+            title: title.into(),
+            losses: Vec::new(),
+            export: None,
+            confirm_label: confirm_label.into(),
+        }
+    }
+
+    /// Adds a line saying what will be lost.
+    pub(crate) fn loses(mut self, loss: impl Into<String>) -> Self {
+        self.losses.push(loss.into());
+        self
+    }
+
+    /// Offers to copy `text` to the clipboard before going ahead.
+    pub(crate) fn export(mut self, text: String) -> Self {
+        self.export = Some(text);
+        self
+    }
+}
+
+/// Shows the confirmation in `pending` over everything else, blocking the rest of the app until
+/// it's answered. Returns the action once confirmed.
+pub(crate) fn show<T>(ctx: &egui::Context, pending: &mut Option<Confirm<T>>) -> Option<T> {
+    let confirm = pending.as_ref()?;
+    let mut answer = None;
+
+    // a dimmed backdrop swallows clicks meant for whatever is behind the dialog
+    let screen = ctx.input().screen_rect();
+    egui::Area::new("confirm-backdrop")
+        .order(Order::Foreground)
+        .fixed_pos(screen.min)
+        .show(ctx, |ui| {
+            ui.painter()
+                .rect_filled(screen, 0.0, Color32::from_black_alpha(140));
+            ui.allocate_rect(screen, Sense::click());
+        });
+
+    let dialog = Id::new("confirm-dialog");
+    ctx.move_to_top(LayerId::new(Order::Foreground, dialog));
+    egui::Area::new(dialog)
+        .order(Order::Foreground)
+        .anchor(Align2::CENTER_CENTER, Vec2::ZERO)
+        .show(ctx, |ui| {
+            egui::Frame::window(ui.style()).show(ui, |ui| {
+                ui.set_max_width(360.0);
+                ui.heading(&confirm.title);
+                for loss in &confirm.losses {
+                    ui.label(format!("• {loss}"));
+                }
+                if let Some(text) = &confirm.export {
+                    ui.add_space(4.0);
+                    if ui
+                        .button(tr!("confirm-copy"))
+                        .on_hover_text(tr!("confirm-copy-hint"))
+                        .clicked()
+                    {
+                        ui.output().copied_text = text.clone();
+                    }
+                }
+                ui.separator();
+                ui.horizontal(|ui| {
+                    let go = RichText::new(&confirm.confirm_label).color(Color32::RED);
+                    if ui.button(go).clicked() {
+                        answer = Some(true);
+                    }
+                    if ui.button(tr!("confirm-cancel")).clicked() {
+                        answer = Some(false);
+                    }
+                });
+            });
+        });
+
+    if ctx
+        .input_mut()
+        .consume_key(egui::Modifiers::NONE, egui::Key::Escape)
+    {
+        answer = Some(false);
+    }
+    match answer? {
+        true => pending.take().map(|confirm| confirm.action),
+        false => {
+            *pending = None;
+            None
+        }
+    }
+}
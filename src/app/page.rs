@@ -1,21 +1,27 @@
-use crate::hat::Person;
+use egui::{Color32, RichText};
 
+use crate::app::confirm::{self, Confirm};
+use crate::avatar::{self, Avatar, Photos};
+use crate::hat::{Hat, Pair, Person};
+use crate::i18n;
+use crate::import::{self, vcard};
+use crate::share;
+use crate::valid_pair;
+
+mod settings;
 mod wheel;
+pub(crate) use settings::{display_settings, SettingsPage};
 pub(crate) use wheel::*;
 
-#[derive(serde::Deserialize, serde::Serialize, PartialEq, Eq)]
+#[derive(serde::Deserialize, serde::Serialize, PartialEq, Eq, Default)]
 pub enum Page {
     People,
     Wheel,
+    Settings,
+    #[default]
     About,
 }
 
-impl Default for Page {
-    fn default() -> Self {
-        Page::About
-    }
-}
-
 pub(crate) fn display_about(ctx: &egui::Context) {
     egui::CentralPanel::default().show(ctx, |ui| {
         egui::warn_if_debug_build(ui);
@@ -23,9 +29,9 @@ pub(crate) fn display_about(ctx: &egui::Context) {
         ui.with_layout(egui::Layout::bottom_up(egui::Align::LEFT), |ui| {
             ui.horizontal(|ui| {
                 ui.spacing_mut().item_spacing.x = 0.0;
-                ui.label("powered by ");
+                ui.label(format!("{} ", tr!("about-powered-by")));
                 ui.hyperlink_to("egui", "https://github.com/emilk/egui");
-                ui.label(" and ");
+                ui.label(format!(" {} ", tr!("about-and")));
                 ui.hyperlink_to(
                     "eframe",
                     "https://github.com/emilk/egui/tree/master/crates/eframe",
@@ -40,74 +46,734 @@ pub(crate) fn display_about(ctx: &egui::Context) {
 #[serde(default)] // if we add new fields, give them default values when deserializing old state
 pub(crate) struct PeoplePage {
     pub(crate) person: Person,
+    fixed_giver: Option<Person>,
+    fixed_receiver: Option<Person>,
+    fixed_error: Option<String>,
+    /// Path typed into a picture menu, for loading a photo from disk.
+    photo_path: String,
+    avatar_error: Option<String>,
+    /// Moves the keyboard focus to the new person's name next frame.
+    #[serde(skip)]
+    pub(crate) focus_name: bool,
+    #[serde(skip)]
+    confirm_remove: Option<Confirm<Person>>,
+    /// Text pasted into the bulk entry window, kept if it's closed before adding.
+    bulk_text: String,
+    #[serde(skip)]
+    bulk_open: bool,
+    /// Which part of pasted or opened contacts becomes each person's group.
+    group_field: vcard::GroupField,
+    /// Path typed into the bulk entry window, for opening a contacts file from disk.
+    contacts_path: String,
+    #[serde(skip)]
+    contacts_error: Option<String>,
+    /// Why contacts were left out of the last ones added, shown until the window is closed.
+    #[serde(skip)]
+    skipped_contacts: Vec<String>,
+    /// Only people whose name or group contains this are listed.
+    #[serde(skip)]
+    filter: String,
+    /// Where in the list the person being dragged by their handle came from.
+    #[serde(skip)]
+    dragging: Option<usize>,
+    #[serde(skip)]
+    share_open: bool,
+    /// Where the web app is, for making links to share. Typed in on native builds, and read from
+    /// the browser on the web.
+    pub(crate) web_address: String,
+    /// A link someone was sent, pasted into the share window.
+    #[serde(skip)]
+    pasted_link: String,
+    /// Set when the pasted link should be opened, which replaces the people list.
+    #[serde(skip)]
+    pub(crate) link_to_open: Option<String>,
 }
 
-pub(crate) fn dipslay_people(page: &mut PeoplePage, people: &mut Vec<Person>, ctx: &egui::Context) {
-    egui::TopBottomPanel::bottom("new-person").show(ctx, |ui| {
-        ui.edit_person(&mut page.person);
+impl PeoplePage {
+    pub(crate) fn confirming(&self) -> bool {
+        self.confirm_remove.is_some()
+    }
+
+    /// Asks before removing someone, listing the fixed pairs that go with them.
+    fn request_remove(&mut self, person: &Person, people: &[Person], fixed_pairs: &[Pair]) {
+        let mut confirm = Confirm::new(
+            person.clone(),
+            tr!("confirm-remove-title", name = person.name),
+            tr!("confirm-remove"),
+        )
+        .loses(tr!("confirm-remove-person", name = person.name));
+        for pair in fixed_pairs {
+            if pair.giver == *person || pair.receiver == *person {
+                confirm = confirm.loses(tr!(
+                    "confirm-remove-fixed",
+                    giver = pair.giver.name,
+                    receiver = pair.receiver.name
+                ));
+            }
+        }
+        if person.avatar.is_some() {
+            confirm = confirm.loses(tr!("confirm-remove-picture"));
+        }
+        self.confirm_remove = Some(confirm.export(people_text(people, fixed_pairs)));
+    }
+}
+
+/// Everyone and the fixed pairs, one per line, for keeping a copy.
+pub(crate) fn people_text(people: &[Person], fixed_pairs: &[Pair]) -> String {
+    let people = people
+        .iter()
+        .map(|person| format!("{} - {}\n", person.name, person.group));
+    let pairs = fixed_pairs
+        .iter()
+        .map(|pair| format!("{} ==> {}\n", pair.giver.name, pair.receiver.name));
+    people.chain(pairs).collect()
+}
+
+pub(crate) fn dipslay_people(
+    page: &mut PeoplePage,
+    people: &mut Vec<Person>,
+    fixed_pairs: &mut Vec<Pair>,
+    photos: &mut Photos,
+    ctx: &egui::Context,
+) {
+    egui::SidePanel::right("fixed-pairs")
+        .show(ctx, |ui| fixed_pairs_panel(ui, page, people, fixed_pairs));
+
+    if let Some(person) = confirm::show(ctx, &mut page.confirm_remove) {
+        if let Some(index) = people.iter().position(|p| *p == person) {
+            people.remove(index);
+        }
+        fixed_pairs.retain(|pair| pair.giver != person && pair.receiver != person);
+    }
+
+    let dropped = match dropped_file(ctx, photos) {
+        Some(Dropped::Contacts(text)) => {
+            page.bulk_text = text;
+            page.contacts_error = None;
+            page.bulk_open = true;
+            None
+        }
+        Some(Dropped::Photo(photo)) => Some(photo),
+        None => None,
+    };
 
-        if ui.button("Add").clicked() {
-            let person = std::mem::replace(&mut page.person, Person::new("", ""));
-            people.push(person);
+    egui::TopBottomPanel::bottom("new-person").show(ctx, |ui| {
+        let name = ui.edit_person(&mut page.person);
+        if std::mem::take(&mut page.focus_name) {
+            name.request_focus();
         }
+        let submitted = name.lost_focus() && ui.input().key_pressed(egui::Key::Enter);
+        ui.horizontal(|ui| {
+            ui.label(tr!("people-picture"));
+            if let Some(avatar) = &page.person.avatar {
+                ui.avatar(avatar, 24.0);
+            }
+            avatar_menu(
+                ui,
+                &mut page.person.avatar,
+                photos,
+                &mut page.photo_path,
+                &mut page.avatar_error,
+            );
+        });
+        if let Some(msg) = &page.avatar_error {
+            ui.colored_label(Color32::RED, msg);
+        }
+
+        ui.horizontal(|ui| {
+            if ui.button(tr!("people-add")).clicked()
+                || submitted && !page.person.name.trim().is_empty()
+            {
+                let person = std::mem::replace(&mut page.person, Person::new("", ""));
+                people.push(person);
+                if submitted {
+                    // stay in the name field, ready for the next person
+                    name.request_focus();
+                }
+            }
+            if ui.button(tr!("bulk-open")).clicked() {
+                page.bulk_open = true;
+            }
+            if ui.button(tr!("share-button")).clicked() {
+                page.share_open = true;
+            }
+        });
     });
 
+    if page.bulk_open {
+        bulk_window(ctx, page, people);
+    }
+    if page.share_open {
+        share_window(ctx, page, people, fixed_pairs);
+    }
+
     egui::CentralPanel::default().show(ctx, |ui| {
+        if !ctx.input().raw.hovered_files.is_empty() {
+            ui.label(tr!("people-drop-hint"));
+        }
+        let pointer = ctx.input().pointer.hover_pos();
+
+        i18n::horizontal(ui, |ui| {
+            ui.add(
+                egui::TextEdit::singleline(&mut page.filter)
+                    .hint_text(tr!("people-search"))
+                    .desired_width(160.0),
+            );
+            if !page.filter.is_empty() && ui.small_button("X").clicked() {
+                page.filter.clear();
+            }
+            ui.separator();
+            ui.label(tr!("people-sort"));
+            if ui.button(tr!("people-sort-name")).clicked() {
+                people.sort_by_cached_key(|person| person.name.to_lowercase());
+            }
+            if ui.button(tr!("people-sort-group")).clicked() {
+                people.sort_by_cached_key(|person| {
+                    (person.group.to_lowercase(), person.name.to_lowercase())
+                });
+            }
+        });
+        ui.weak(tr!("people-order-hint"));
+
         egui::ScrollArea::vertical()
             .auto_shrink([false, false])
             .show(ui, |ui| {
-                let mut to_swap = None;
+                let filter = page.filter.trim().to_lowercase();
                 let mut to_remove = None;
-                let count = people.len();
-                for (idx, person) in people.iter().enumerate() {
-                    ui.group(|ui| {
-                        ui.horizontal(|ui| {
-                            ui.person(person);
-                            if idx > 0 && ui.button("/\\").clicked() {
-                                to_swap = Some((idx - 1, idx));
+                let mut changed_avatar = None;
+                let mut dropped_on = None;
+                let mut rows = Vec::new();
+                for (idx, person) in people.iter_mut().enumerate() {
+                    if !filter.is_empty()
+                        && !person.name.to_lowercase().contains(&filter)
+                        && !person.group.to_lowercase().contains(&filter)
+                    {
+                        continue;
+                    }
+                    let row = ui.group(|ui| {
+                        i18n::horizontal(ui, |ui| {
+                            let handle = ui
+                                .add(egui::Label::new("☰").sense(egui::Sense::drag()))
+                                .on_hover_cursor(egui::CursorIcon::Grab)
+                                .on_hover_text(tr!("people-drag-hint"));
+                            if handle.drag_started() {
+                                page.dragging = Some(idx);
                             }
-                            if idx < count - 1 && ui.button("\\/").clicked() {
-                                to_swap = Some((idx, idx+1))
+                            ui.person(person);
+                            if avatar_menu(
+                                ui,
+                                &mut person.avatar,
+                                photos,
+                                &mut page.photo_path,
+                                &mut page.avatar_error,
+                            ) {
+                                changed_avatar = Some(idx);
                             }
                             if ui.button("X").clicked() {
                                 to_remove = Some(idx);
                             }
                         });
                     });
+                    if pointer.map_or(false, |pos| row.response.rect.contains(pos)) {
+                        dropped_on = Some(idx);
+                    }
+                    rows.push((idx, row.response.rect));
+                }
+
+                if let Some(from) = page.dragging {
+                    drag_person(ui, people, from, &rows);
+                    if ui.input().pointer.any_released() {
+                        page.dragging = None;
+                    }
                 }
 
-                if let Some((first, second)) = to_swap {
-                    people.swap(first, second);
+                if let Some(photo) = dropped {
+                    match (photo, dropped_on) {
+                        (Ok(avatar), Some(idx)) => {
+                            people[idx].avatar = Some(avatar);
+                            changed_avatar = Some(idx);
+                        }
+                        (Ok(avatar), None) => page.person.avatar = Some(avatar),
+                        (Err(msg), _) => page.avatar_error = Some(msg),
+                    }
+                }
+                if let Some(idx) = changed_avatar {
+                    // keep the copies in fixed pairs showing the new picture too
+                    let person = &people[idx];
+                    for pair in fixed_pairs.iter_mut() {
+                        for member in [&mut pair.giver, &mut pair.receiver] {
+                            if member == person {
+                                member.avatar = person.avatar.clone();
+                            }
+                        }
+                    }
                 }
                 if let Some(index) = to_remove {
-                    people.remove(index);
+                    let person = people[index].clone();
+                    page.request_remove(&person, people, fixed_pairs);
+                }
+            });
+    });
+}
+
+/// A link that opens everyone and the fixed pairs in someone else's browser.
+fn share_window(
+    ctx: &egui::Context,
+    page: &mut PeoplePage,
+    people: &[Person],
+    fixed_pairs: &[Pair],
+) {
+    egui::Window::new(tr!("share-title"))
+        .id(egui::Id::new("share-window"))
+        .open(&mut page.share_open)
+        .collapsible(false)
+        .default_width(420.0)
+        .show(ctx, |ui| {
+            ui.label(tr!("share-received"));
+            i18n::horizontal(ui, |ui| {
+                ui.add(
+                    egui::TextEdit::singleline(&mut page.pasted_link)
+                        .hint_text(tr!("share-paste-hint")),
+                );
+                let paste = page.pasted_link.trim();
+                if ui
+                    .add_enabled(!paste.is_empty(), egui::Button::new(tr!("share-open")))
+                    .clicked()
+                {
+                    page.link_to_open = Some(std::mem::take(&mut page.pasted_link));
+                }
+            });
+            ui.separator();
+
+            ui.label(tr!("share-help"));
+            #[cfg(not(target_arch = "wasm32"))]
+            ui.horizontal(|ui| {
+                ui.label(tr!("share-address"));
+                ui.add(
+                    egui::TextEdit::singleline(&mut page.web_address)
+                        .hint_text("https://example.github.io/gift_exchange_wheel/"),
+                );
+            });
+            let address = page.web_address.trim();
+            if address.is_empty() {
+                ui.weak(tr!("share-needs-address"));
+                return;
+            }
+
+            let link = share::link(address, people, fixed_pairs);
+            ui.add(
+                egui::TextEdit::multiline(&mut link.as_str())
+                    .desired_rows(3)
+                    .desired_width(f32::INFINITY),
+            );
+            i18n::horizontal(ui, |ui| {
+                if ui.button(tr!("share-copy")).clicked() {
+                    ui.output().copied_text = link.clone();
+                }
+                ui.weak(tr!("share-length", length = link.len()));
+            });
+            if people.iter().any(|person| {
+                matches!(person.avatar, Some(Avatar::Photo { .. })) || !person.email.is_empty()
+            }) {
+                ui.weak(tr!("share-left-out"));
+            }
+            // some mail and chat apps cut links off around here
+            if link.len() > 2000 {
+                ui.colored_label(Color32::from_rgb(200, 120, 0), tr!("share-too-long"));
+            }
+        });
+}
+
+/// Shows where the person being dragged will land, and moves them there when let go.
+fn drag_person(
+    ui: &mut egui::Ui,
+    people: &mut Vec<Person>,
+    from: usize,
+    rows: &[(usize, egui::Rect)],
+) {
+    ui.output().cursor_icon = egui::CursorIcon::Grabbing;
+    let (Some(pointer), Some(&(last_idx, last))) = (ui.input().pointer.interact_pos(), rows.last())
+    else {
+        return;
+    };
+
+    // drop in front of the first row whose middle is below the pointer, or else after the last
+    let (to, y) = rows
+        .iter()
+        .find(|(_, rect)| pointer.y < rect.center().y)
+        .map_or((last_idx + 1, last.bottom()), |&(idx, rect)| {
+            (idx, rect.top())
+        });
+    let stroke = ui.visuals().selection.stroke;
+    ui.painter()
+        .hline(last.x_range(), y, egui::Stroke::new(2.0, stroke.color));
+
+    if ui.input().pointer.any_released() && from < people.len() {
+        let person = people.remove(from);
+        let to = if to > from { to - 1 } else { to };
+        people.insert(to.min(people.len()), person);
+    }
+}
+
+/// A window for pasting in many people at once, showing what each line will add before it does.
+fn bulk_window(ctx: &egui::Context, page: &mut PeoplePage, people: &mut Vec<Person>) {
+    let mut open = true;
+    egui::Window::new(tr!("bulk-title"))
+        .id(egui::Id::new("bulk-entry"))
+        .open(&mut open)
+        .default_width(420.0)
+        .show(ctx, |ui| {
+            ui.label(tr!("bulk-help"));
+            ui.weak(tr!("bulk-contacts-help"));
+            #[cfg(not(target_arch = "wasm32"))]
+            ui.horizontal(|ui| {
+                ui.add(
+                    egui::TextEdit::singleline(&mut page.contacts_path)
+                        .hint_text(tr!("bulk-contacts-path")),
+                );
+                if ui.button(tr!("bulk-open-file")).clicked() {
+                    let path = page.contacts_path.trim();
+                    match std::fs::read(path) {
+                        Ok(bytes) => {
+                            page.bulk_text = String::from_utf8_lossy(&bytes).into_owned();
+                            page.contacts_error = None;
+                        }
+                        Err(err) => {
+                            page.contacts_error = Some(tr!("open-failed", path = path, error = err))
+                        }
+                    }
                 }
             });
+            if let Some(msg) = &page.contacts_error {
+                ui.colored_label(Color32::RED, msg);
+            }
+            ui.add(
+                egui::TextEdit::multiline(&mut page.bulk_text)
+                    .hint_text("Alice, Smith\nBob, Smith\nJones: Carol, Dave")
+                    .desired_rows(6)
+                    .desired_width(f32::INFINITY)
+                    .code_editor(),
+            );
+
+            let contacts = vcard::is_vcard(&page.bulk_text);
+            if contacts {
+                egui::ComboBox::from_label(tr!("vcard-group-from"))
+                    .selected_text(page.group_field.label())
+                    .show_ui(ui, |ui| {
+                        for field in vcard::GroupField::ALL {
+                            ui.selectable_value(&mut page.group_field, field, field.label());
+                        }
+                    });
+            }
+            let lines = import::parse_people(&page.bulk_text, page.group_field, people);
+            let count: usize = lines.iter().map(|line| line.people.len()).sum();
+            let has_errors = lines.iter().any(|line| !line.errors.is_empty());
+
+            egui::ScrollArea::vertical()
+                .max_height(240.0)
+                .show(ui, |ui| {
+                    egui::Grid::new("bulk-preview")
+                        .num_columns(2)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for line in &lines {
+                                ui.weak(line.number.to_string());
+                                ui.vertical(|ui| {
+                                    for person in &line.people {
+                                        ui.person(person);
+                                    }
+                                    for error in &line.errors {
+                                        ui.colored_label(Color32::RED, error);
+                                    }
+                                });
+                                ui.end_row();
+                            }
+                        });
+                });
+
+            ui.separator();
+            ui.horizontal(|ui| {
+                let add = egui::Button::new(tr!("bulk-add", count = count));
+                if ui.add_enabled(count > 0, add).clicked() {
+                    // keep the lines that had problems, so they can be fixed and added after
+                    let mut leftover = String::new();
+                    let mut skipped = Vec::new();
+                    for line in lines {
+                        people.extend(line.people);
+                        if !line.errors.is_empty() {
+                            leftover.push_str(&line.text);
+                            leftover.push('\n');
+                            skipped.extend(line.errors);
+                        }
+                    }
+                    // cards can't be cut apart like lines, so contacts are cleared and the ones
+                    // left out are listed instead
+                    page.skipped_contacts = if contacts { skipped } else { Vec::new() };
+                    page.bulk_text = if contacts { String::new() } else { leftover };
+                    if !has_errors {
+                        page.bulk_open = false;
+                    }
+                }
+                if has_errors && !contacts {
+                    ui.weak(tr!("bulk-errors-kept"));
+                }
+            });
+            if !page.skipped_contacts.is_empty() {
+                ui.label(tr!(
+                    "bulk-contacts-skipped",
+                    count = page.skipped_contacts.len()
+                ));
+                for msg in &page.skipped_contacts {
+                    ui.colored_label(Color32::RED, msg);
+                }
+            }
+        });
+    if !open {
+        page.bulk_open = false;
+    }
+    if !page.bulk_open {
+        page.skipped_contacts.clear();
+    }
+}
+
+enum Dropped {
+    Photo(Result<Avatar, String>),
+    /// The text of a contacts file.
+    Contacts(String),
+}
+
+/// Reads a photo or contacts file dropped onto the window this frame, if there was one.
+fn dropped_file(ctx: &egui::Context, photos: &mut Photos) -> Option<Dropped> {
+    let file = ctx.input().raw.dropped_files.first().cloned()?;
+    let bytes = match (&file.bytes, &file.path) {
+        (Some(bytes), _) => bytes.to_vec(),
+        (None, Some(path)) => match std::fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                let msg = tr!("open-failed", path = path.display(), error = err);
+                return Some(Dropped::Photo(Err(msg)));
+            }
+        },
+        (None, None) => return None,
+    };
+    let start = String::from_utf8_lossy(&bytes[..bytes.len().min(64)]);
+    if vcard::is_vcard(&start) {
+        let text = String::from_utf8_lossy(&bytes).into_owned();
+        return Some(Dropped::Contacts(text));
+    }
+    Some(Dropped::Photo(photos.add(&bytes)))
+}
+
+/// A button opening a menu to pick an emoji or photo for someone. Returns whether it changed.
+fn avatar_menu(
+    ui: &mut egui::Ui,
+    avatar: &mut Option<Avatar>,
+    photos: &mut Photos,
+    photo_path: &mut String,
+    error: &mut Option<String>,
+) -> bool {
+    let mut changed = false;
+    ui.menu_button(tr!("avatar-menu"), |ui| {
+        egui::Grid::new("built-in-avatars").show(ui, |ui| {
+            for (idx, emoji) in avatar::BUILT_IN.iter().enumerate() {
+                if ui.button(RichText::new(*emoji).size(20.0)).clicked() {
+                    *avatar = Some(Avatar::Emoji(emoji.to_string()));
+                    changed = true;
+                    ui.close_menu();
+                }
+                if idx % 8 == 7 {
+                    ui.end_row();
+                }
+            }
+        });
+        if avatar.is_some() && ui.button(tr!("avatar-none")).clicked() {
+            *avatar = None;
+            changed = true;
+            ui.close_menu();
+        }
+
+        ui.separator();
+        #[cfg(not(target_arch = "wasm32"))]
+        ui.horizontal(|ui| {
+            ui.add(egui::TextEdit::singleline(photo_path).hint_text(tr!("avatar-path-hint")));
+            if ui.button(tr!("avatar-load")).clicked() {
+                let photo = std::fs::read(photo_path.trim())
+                    .map_err(|err| tr!("open-failed", path = photo_path.trim(), error = err))
+                    .and_then(|bytes| photos.add(&bytes));
+                match photo {
+                    Ok(photo) => {
+                        *avatar = Some(photo);
+                        *error = None;
+                        changed = true;
+                        ui.close_menu();
+                    }
+                    Err(msg) => *error = Some(msg),
+                }
+            }
+        });
+        #[cfg(target_arch = "wasm32")]
+        let _ = (photos, photo_path, error);
+        ui.weak(tr!("avatar-drop-hint"));
     });
+    changed
+}
+
+fn fixed_pairs_panel(
+    ui: &mut egui::Ui,
+    page: &mut PeoplePage,
+    people: &[Person],
+    fixed_pairs: &mut Vec<Pair>,
+) {
+    ui.heading(tr!("fixed-heading"));
+    ui.label(tr!("fixed-description"));
+
+    let mut to_remove = None;
+    for (idx, pair) in fixed_pairs.iter().enumerate() {
+        ui.horizontal(|ui| {
+            ui.person(&pair.giver);
+            ui.label("==>");
+            ui.person(&pair.receiver);
+            if ui.button("X").clicked() {
+                to_remove = Some(idx);
+            }
+        });
+    }
+    if let Some(index) = to_remove {
+        fixed_pairs.remove(index);
+        page.fixed_error = None;
+    }
+
+    ui.separator();
+    person_combo_box(ui, tr!("fixed-giver"), &mut page.fixed_giver, people);
+    person_combo_box(ui, tr!("fixed-receiver"), &mut page.fixed_receiver, people);
+
+    if ui.button(tr!("fixed-add")).clicked() {
+        match (page.fixed_giver.take(), page.fixed_receiver.take()) {
+            (Some(giver), Some(receiver)) => {
+                let pair = Pair::fixed(giver, receiver);
+                page.fixed_error = check_fixed_pair(&pair, people, fixed_pairs).err();
+                if page.fixed_error.is_none() {
+                    fixed_pairs.push(pair);
+                }
+            }
+            _ => page.fixed_error = Some(tr!("fixed-pick-both").into()),
+        }
+    }
+    if let Some(msg) = &page.fixed_error {
+        ui.colored_label(Color32::RED, msg);
+    }
+}
+
+fn person_combo_box(
+    ui: &mut egui::Ui,
+    label: &str,
+    selected: &mut Option<Person>,
+    people: &[Person],
+) {
+    let text = selected.as_ref().map(|p| p.name.as_str()).unwrap_or("");
+    egui::ComboBox::from_label(label)
+        .selected_text(text)
+        .show_ui(ui, |ui| {
+            for person in people {
+                ui.selectable_value(selected, Some(person.clone()), &person.name);
+            }
+        });
+}
+
+fn check_fixed_pair(pair: &Pair, people: &[Person], fixed_pairs: &[Pair]) -> Result<(), String> {
+    if pair.giver == pair.receiver {
+        return Err(tr!("fixed-self").into());
+    }
+    if fixed_pairs.iter().any(|p| p.giver == pair.giver) {
+        return Err(tr!("fixed-already-giving", name = pair.giver.name));
+    }
+    if fixed_pairs.iter().any(|p| p.receiver == pair.receiver) {
+        return Err(tr!("fixed-already-receiving", name = pair.receiver.name));
+    }
+
+    let mut hat = Hat::with_people(people.to_vec());
+    for fixed in fixed_pairs.iter().chain(std::iter::once(pair)) {
+        hat.fix_pair(fixed);
+    }
+    if !hat.valid_solution_exists(&valid_pair) {
+        return Err(tr!("fixed-impossible").into());
+    }
+
+    Ok(())
 }
 
 trait UiExtensions {
+    fn avatar(&mut self, avatar: &Avatar, size: f32);
     fn person(&mut self, person: &Person);
-    fn edit_person(&mut self, person: &mut Person);
+    /// Returns the name field, so it can be focused.
+    fn edit_person(&mut self, person: &mut Person) -> egui::Response;
 }
 impl UiExtensions for egui::Ui {
+    fn avatar(&mut self, avatar: &Avatar, size: f32) {
+        match avatar {
+            Avatar::Emoji(emoji) => {
+                self.label(RichText::new(emoji).size(size * 0.8));
+            }
+            Avatar::Photo { .. } => {
+                if let Some(texture) = avatar.texture(self.ctx()) {
+                    self.image(texture.id(), [size, size]);
+                }
+            }
+        }
+    }
+
     fn person(&mut self, person: &Person) {
-        self.horizontal(|ui| {
-            ui.label(&person.name);
+        i18n::horizontal(self, |ui| {
+            if let Some(avatar) = &person.avatar {
+                ui.avatar(avatar, 20.0);
+            }
+            let name = ui.label(&person.name);
+            if !person.email.is_empty() {
+                name.on_hover_text(&person.email);
+            }
             ui.label("-");
             ui.label(&person.group);
         });
     }
 
-    fn edit_person(&mut self, person: &mut Person) {
+    fn edit_person(&mut self, person: &mut Person) -> egui::Response {
+        let name = self
+            .horizontal(|ui| {
+                ui.label(tr!("person-name"));
+                ui.text_edit_singleline(&mut person.name)
+            })
+            .inner;
+
         self.horizontal(|ui| {
-            ui.label("Name:");
-            ui.text_edit_singleline(&mut person.name);
+            ui.label(tr!("person-group"));
+            ui.text_edit_singleline(&mut person.group);
         });
 
         self.horizontal(|ui| {
-            ui.label("Group:");
-            ui.text_edit_singleline(&mut person.group);
+            ui.label(tr!("person-email"));
+            ui.add(egui::TextEdit::singleline(&mut person.email).hint_text(tr!("person-optional")));
         });
+        name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn refuses_fixed_pairs_that_leave_no_solution() {
+        let people: Vec<_> = ["Alice", "Bob", "Carol"]
+            .into_iter()
+            .map(|name| Person::new(name, name))
+            .collect();
+        let fixed = [Pair::fixed(people[0].clone(), people[1].clone())];
+        let back = Pair::fixed(people[1].clone(), people[0].clone());
+        assert_eq!(
+            check_fixed_pair(&back, &people, &fixed),
+            Err(tr!("fixed-impossible").into())
+        );
+        let onward = Pair::fixed(people[1].clone(), people[2].clone());
+        assert_eq!(check_fixed_pair(&onward, &people, &fixed), Ok(()));
     }
 }
@@ -0,0 +1,263 @@
+use egui::Color32;
+
+use crate::app::settings::{
+    Easing, Palette, Settings, ShortLabels, SpinSettings, Theme, WedgeSizes,
+};
+use crate::app::shortcuts::{Action, Shortcut, Shortcuts};
+
+use crate::i18n::Language;
+
+use super::SpinPreview;
+
+#[derive(serde::Deserialize, serde::Serialize, Default)]
+#[serde(default)] // if we add new fields, give them default values when deserializing old state
+pub(crate) struct SettingsPage {
+    preview: SpinPreview,
+    /// The action waiting for a key press to become its new shortcut.
+    #[serde(skip)]
+    recording: Option<Action>,
+}
+
+impl SettingsPage {
+    /// Whether key presses are being taken as a new shortcut, rather than running shortcuts.
+    pub(crate) fn recording_shortcut(&self) -> bool {
+        self.recording.is_some()
+    }
+}
+
+pub(crate) fn display_settings(
+    page: &mut SettingsPage,
+    settings: &mut Settings,
+    sound_unavailable: Option<&str>,
+    ctx: &egui::Context,
+) {
+    egui::SidePanel::right("spin-preview").show(ctx, |ui| {
+        ui.heading(tr!("settings-preview"));
+        page.preview.show(ui, settings);
+    });
+
+    egui::CentralPanel::default().show(ctx, |ui| {
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            ui.heading(tr!("settings-language"));
+            ui.horizontal(|ui| {
+                for language in Language::ALL {
+                    ui.selectable_value(&mut settings.language, language, language.native_name());
+                }
+            });
+
+            ui.separator();
+            ui.heading(tr!("settings-theme"));
+            ui.horizontal(|ui| {
+                for theme in Theme::ALL {
+                    ui.selectable_value(&mut settings.theme, theme, theme.label());
+                }
+            });
+
+            ui.separator();
+            ui.heading(tr!("settings-wheel-colors"));
+            for palette in Palette::ALL {
+                ui.horizontal(|ui| {
+                    ui.radio_value(&mut settings.palette, palette, palette.label());
+                    if palette != Palette::Custom {
+                        swatches(ui, palette.colors());
+                    }
+                });
+            }
+
+            if settings.palette == Palette::Custom {
+                if settings.custom_colors.is_empty() {
+                    settings.custom_colors = Palette::Classic.colors().to_vec();
+                }
+                custom_colors(ui, &mut settings.custom_colors);
+            }
+
+            ui.separator();
+            ui.heading(tr!("settings-wedge-sizes"));
+            for wedge_sizes in WedgeSizes::ALL {
+                ui.radio_value(&mut settings.wedge_sizes, wedge_sizes, wedge_sizes.label());
+            }
+            ui.checkbox(&mut settings.narrow_before_spin, tr!("settings-narrow"));
+
+            ui.separator();
+            ui.heading(tr!("settings-wheel-labels"));
+            ui.checkbox(&mut settings.curved_labels, tr!("settings-curved"));
+            ui.horizontal(|ui| {
+                ui.label(tr!("settings-short-labels"));
+                for short_labels in ShortLabels::ALL {
+                    ui.radio_value(
+                        &mut settings.short_labels,
+                        short_labels,
+                        short_labels.label(),
+                    );
+                }
+            });
+
+            ui.separator();
+            ui.heading(tr!("settings-effects"));
+            let mut sound_on = !settings.muted && sound_unavailable.is_none();
+            ui.add_enabled(
+                sound_unavailable.is_none(),
+                egui::Checkbox::new(&mut sound_on, tr!("settings-sound")),
+            );
+            match sound_unavailable {
+                None => settings.muted = !sound_on,
+                Some(reason) => {
+                    ui.weak(reason);
+                }
+            }
+            let mut celebrate = !settings.reduce_motion;
+            ui.checkbox(&mut celebrate, tr!("settings-confetti"));
+            settings.reduce_motion = !celebrate;
+
+            ui.separator();
+            ui.heading(tr!("settings-spin"));
+            spin_settings(ui, &mut settings.spin);
+
+            ui.separator();
+            ui.heading(tr!("settings-shortcuts"));
+            shortcut_settings(ui, &mut page.recording, &mut settings.shortcuts);
+        });
+    });
+}
+
+fn shortcut_settings(ui: &mut egui::Ui, recording: &mut Option<Action>, shortcuts: &mut Shortcuts) {
+    if let Some(action) = *recording {
+        let pressed = ui.input().events.iter().find_map(|event| match event {
+            egui::Event::Key {
+                key,
+                pressed: true,
+                modifiers,
+            } => Some((*key, *modifiers)),
+            _ => None,
+        });
+        match pressed {
+            Some((egui::Key::Escape, _)) => *recording = None,
+            Some((key, modifiers)) => {
+                shortcuts.set(action, Some(Shortcut { modifiers, key }));
+                *recording = None;
+            }
+            None => (),
+        }
+    }
+
+    egui::Grid::new("shortcut-settings")
+        .num_columns(3)
+        .show(ui, |ui| {
+            for action in Action::ALL {
+                ui.label(action.label());
+                let text = match *recording {
+                    Some(recording) if recording == action => tr!("settings-press-key").to_owned(),
+                    _ => shortcuts.describe(action, ui.ctx()),
+                };
+                if ui
+                    .button(text)
+                    .on_hover_text(tr!("settings-shortcut-hint"))
+                    .clicked()
+                {
+                    *recording = Some(action);
+                }
+                if ui.button(tr!("settings-clear")).clicked() {
+                    shortcuts.set(action, None);
+                }
+                ui.end_row();
+            }
+        });
+    if ui.button(tr!("settings-reset-shortcuts")).clicked() {
+        *shortcuts = Shortcuts::default();
+        *recording = None;
+    }
+}
+
+fn spin_settings(ui: &mut egui::Ui, spin: &mut SpinSettings) {
+    ui.horizontal(|ui| {
+        ui.label(tr!("spin-presets"));
+        if ui.button(tr!("spin-quick")).clicked() {
+            *spin = SpinSettings {
+                windup_time: 0.2,
+                spin_time: 0.3,
+                stop_time: 0.5,
+                top_speed: 12.0,
+                ..spin.clone()
+            };
+        }
+        if ui.button(tr!("spin-standard")).clicked() {
+            *spin = SpinSettings {
+                idle_drift: spin.idle_drift,
+                ..SpinSettings::default()
+            };
+        }
+        if ui.button(tr!("spin-dramatic")).clicked() {
+            *spin = SpinSettings {
+                windup_time: 2.0,
+                spin_time: 7.0,
+                stop_time: 6.0,
+                top_speed: 8.0,
+                easing: Easing::EaseOutCubic,
+                ..spin.clone()
+            };
+        }
+    });
+
+    egui::Grid::new("spin-settings")
+        .num_columns(2)
+        .show(ui, |ui| {
+            ui.label(tr!("spin-wind-up"));
+            ui.add(egui::Slider::new(&mut spin.windup_time, 0.0..=5.0).suffix(" s"));
+            ui.end_row();
+
+            ui.label(tr!("spin-full-speed"));
+            ui.add(egui::Slider::new(&mut spin.spin_time, 0.0..=15.0).suffix(" s"));
+            ui.end_row();
+
+            ui.label(tr!("spin-slow-down"));
+            ui.add(egui::Slider::new(&mut spin.stop_time, 0.0..=10.0).suffix(" s"));
+            ui.end_row();
+
+            ui.label(tr!("spin-top-speed"));
+            ui.add(egui::Slider::new(&mut spin.top_speed, 1.0..=20.0).suffix(" rad/s"));
+            ui.end_row();
+
+            ui.label(tr!("spin-curve"));
+            egui::ComboBox::from_id_source("spin-easing")
+                .selected_text(spin.easing.label())
+                .show_ui(ui, |ui| {
+                    for easing in Easing::ALL {
+                        ui.selectable_value(&mut spin.easing, easing, easing.label());
+                    }
+                });
+            ui.end_row();
+        });
+    ui.checkbox(&mut spin.idle_drift, tr!("spin-idle-drift"));
+    ui.label(tr!(
+        "spin-total-time",
+        seconds = format!("{:.1}", spin.total_time())
+    ));
+}
+
+fn swatches(ui: &mut egui::Ui, colors: &[Color32]) {
+    for &color in colors {
+        let (rect, _) = ui.allocate_exact_size(egui::vec2(16.0, 16.0), egui::Sense::hover());
+        ui.painter().rect_filled(rect, 2.0, color);
+    }
+}
+
+fn custom_colors(ui: &mut egui::Ui, colors: &mut Vec<Color32>) {
+    let mut to_remove = None;
+    ui.horizontal_wrapped(|ui| {
+        for (idx, color) in colors.iter_mut().enumerate() {
+            ui.color_edit_button_srgba(color);
+            if ui.small_button("X").clicked() {
+                to_remove = Some(idx);
+            }
+            ui.add_space(8.0);
+        }
+        if ui.button(tr!("colors-add")).clicked() {
+            colors.push(Color32::WHITE);
+        }
+    });
+    if let Some(idx) = to_remove {
+        if colors.len() > 1 {
+            colors.remove(idx);
+        }
+    }
+}
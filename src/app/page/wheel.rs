@@ -1,29 +1,72 @@
+mod confetti;
+mod export;
+mod label;
 mod spinner;
 
 use egui::{Color32, RichText};
+use rand::seq::SliceRandom;
 
-use crate::hat::{DrawError, Hat, Pair, Person};
+use crate::app::confirm::{self, Confirm};
+use crate::app::settings::{Easing, Settings, SpinSettings, WedgeSizes};
+use crate::app::shortcuts::{Action, Shortcuts};
+use crate::calendar::Event;
+use crate::hat::{DrawError, Hat, Pair, Person, Role};
+use crate::i18n;
+use crate::print::Layout;
+use crate::sound::{Effect, Sound};
 use crate::valid_pair;
 
+use self::confetti::Celebration;
 use self::spinner::{Spinner, SpinnerTarget};
 
 use super::UiExtensions;
-const SPIN_TIME: f32 = 5.0;
 
-#[derive(serde::Deserialize, serde::Serialize, Debug)]
+#[derive(serde::Deserialize, serde::Serialize, Debug, Default)]
 enum WheelState {
+    #[default]
     Idle,
+    /// Shrinking away the wedges the spin can't land on, before the wheel starts turning.
+    Narrowing {
+        pair: Pair,
+        start_time: f32,
+    },
     Windup(Pair),
-    HoldAtTopSpeed { pair: Pair, start_time: f32 },
-    SlowToStop { pair: Pair },
-    Stopped { pair: Pair },
+    HoldAtTopSpeed {
+        pair: Pair,
+        start_time: f32,
+    },
+    SlowToStop {
+        pair: Pair,
+    },
+    Stopped {
+        pair: Pair,
+    },
 }
+/// Seconds spent shrinking away the wedges a spin can't land on.
+const NARROW_TIME: f32 = 1.2;
+
 impl WheelState {
-    fn try_transition(&mut self, spinner: &mut Spinner, time: f32) {
+    /// Moves on to the next stage of the spin once the current one is over. Returns whether the
+    /// wheel just came to rest on its result.
+    fn try_transition(
+        &mut self,
+        spinner: &mut Spinner,
+        time: f32,
+        settings: &SpinSettings,
+    ) -> bool {
+        let was_stopped = matches!(self, WheelState::Stopped { .. });
         *self = match std::mem::take(self) {
             WheelState::Idle => WheelState::Idle,
+            WheelState::Narrowing { pair, start_time } => {
+                if time - start_time >= NARROW_TIME {
+                    spinner.ease_to(settings.top_speed, settings.windup_time, settings.easing);
+                    WheelState::Windup(pair)
+                } else {
+                    WheelState::Narrowing { pair, start_time }
+                }
+            }
             WheelState::Windup(pair) => {
-                if spinner.speed() + 0.05 >= spinner::FULL_SPEED {
+                if spinner.target_reached() {
                     WheelState::HoldAtTopSpeed {
                         pair,
                         start_time: time,
@@ -33,19 +76,16 @@ impl WheelState {
                 }
             }
             WheelState::HoldAtTopSpeed { pair, start_time } => {
-                if time - start_time > SPIN_TIME {
+                if time - start_time > settings.spin_time {
                     //TODO figure out which item should be selected when the spinner stops
-                    spinner.target = SpinnerTarget::Speed(0.0);
+                    spinner.ease_to(0.0, settings.stop_time, settings.easing);
                     WheelState::SlowToStop { pair }
                 } else {
-                    WheelState::HoldAtTopSpeed {
-                        pair,
-                        start_time,
-                    }
+                    WheelState::HoldAtTopSpeed { pair, start_time }
                 }
             }
             WheelState::SlowToStop { pair } => {
-                if spinner.speed() <= 0.05 {
+                if spinner.target_reached() {
                     WheelState::Stopped { pair }
                 } else {
                     WheelState::SlowToStop { pair }
@@ -53,12 +93,66 @@ impl WheelState {
             }
             WheelState::Stopped { pair } => WheelState::Stopped { pair },
         };
+        !was_stopped && matches!(self, WheelState::Stopped { .. })
     }
 }
 
-impl Default for WheelState {
-    fn default() -> Self {
-        Self::Idle
+impl WheelState {
+    fn pair(&self) -> Option<&Pair> {
+        match self {
+            WheelState::Idle => None,
+            WheelState::Narrowing { pair, .. }
+            | WheelState::Windup(pair)
+            | WheelState::HoldAtTopSpeed { pair, .. }
+            | WheelState::SlowToStop { pair }
+            | WheelState::Stopped { pair } => Some(pair),
+        }
+    }
+}
+
+/// How the next giver to spin for is picked from the remaining givers.
+#[derive(serde::Deserialize, serde::Serialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum SpinOrder {
+    /// Top to bottom, as listed on the People page.
+    #[default]
+    InOrder,
+    Random,
+    /// The organizer clicks a name in the Remaining Givers list.
+    Chosen,
+}
+
+impl SpinOrder {
+    fn label(self) -> &'static str {
+        match self {
+            SpinOrder::InOrder => tr!("order-in-order"),
+            SpinOrder::Random => tr!("order-random"),
+            SpinOrder::Chosen => tr!("order-chosen"),
+        }
+    }
+}
+
+/// Difference between the people list and the people the wheel is drawing for.
+#[derive(Default)]
+struct RosterChanges {
+    added: Vec<Person>,
+    removed: Vec<Person>,
+    fixed_added: Vec<Pair>,
+    fixed_removed: Vec<Pair>,
+    /// People were moved around in the list.
+    reordered: bool,
+}
+
+impl RosterChanges {
+    fn is_empty(&self) -> bool {
+        !self.reordered && !self.changed_people()
+    }
+
+    /// Whether anyone or any fixed pair was added or removed, rather than only moved.
+    fn changed_people(&self) -> bool {
+        !self.added.is_empty()
+            || !self.removed.is_empty()
+            || !self.fixed_added.is_empty()
+            || !self.fixed_removed.is_empty()
     }
 }
 
@@ -70,110 +164,982 @@ pub(crate) struct WheelPage {
     drawn_names: Vec<Pair>,
     error_message: Option<String>,
     spinner: Spinner,
+    spin_order: SpinOrder,
+    /// Picked in the side panel when using [`SpinOrder::Chosen`].
+    chosen: Option<Person>,
+    /// The people list as it was when the draw started or was last synced, to tell when it has
+    /// been reordered since.
+    order: Vec<Person>,
+    /// Whether each spin finds a receiver for a giver, or a giver for a receiver.
+    spinning_for: Role,
+    #[serde(skip)]
+    celebration: Option<Celebration>,
+    /// Shows just the wheel and who it's spinning for, big enough to read from across the room.
+    #[serde(skip)]
+    pub(crate) presenting: bool,
+    /// Whether the wheel was spinning last frame, to tell when a spin gets going.
+    #[serde(skip)]
+    was_spinning: bool,
+    /// The last thing said to screen readers about the draw.
+    #[serde(skip)]
+    announcement: String,
+    #[serde(skip)]
+    confirm_restart: Option<Confirm<()>>,
+    /// When and where the exchange is, for exporting to calendars.
+    event: Event,
+    #[serde(skip)]
+    calendar_open: bool,
+    calendar_path: String,
+    /// Whose reminders go in the calendar file, or everyone's if `None`.
+    #[serde(skip)]
+    reminders_for: Option<Person>,
+    /// What happened the last time the calendar was saved.
+    #[serde(skip)]
+    calendar_saved: Option<Result<String, String>>,
+    #[serde(skip)]
+    print_open: bool,
+    print_path: String,
+    print_layout: Layout,
+    #[serde(skip)]
+    print_saved: Option<Result<String, String>>,
 }
 
 impl WheelPage {
-    fn reset(&mut self, people: &[Person]) {
+    /// Restarts the draw, first asking whether to throw away any results already drawn.
+    fn request_restart(
+        &mut self,
+        people: &[Person],
+        fixed_pairs: &[Pair],
+        settings: &SpinSettings,
+    ) {
+        let (drawn, total) = self.drawn_of_total();
+        if drawn == 0 {
+            self.reset(people, fixed_pairs, settings);
+            return;
+        }
+        self.confirm_restart = Some(
+            Confirm::new((), tr!("confirm-restart-title"), tr!("wheel-restart"))
+                .loses(tr!("confirm-restart-pairs", drawn = drawn, total = total))
+                .export(self.results_text()),
+        );
+    }
+
+    /// How many pairs have been drawn and how many there are to draw in all, leaving out fixed
+    /// pairs since restarting keeps them.
+    fn drawn_of_total(&self) -> (usize, usize) {
+        let in_flight = self.state.pair().map_or(0, |_| 1);
+        let drawn = self.drawn_names.iter().filter(|pair| !pair.fixed).count() + in_flight;
+        (drawn, drawn + self.hat.givers().len())
+    }
+
+    pub(crate) fn confirming(&self) -> bool {
+        self.confirm_restart.is_some()
+    }
+
+    /// The pairs drawn so far, including one the wheel is showing but hasn't added yet.
+    fn results(&self) -> Vec<&Pair> {
+        let shown = match &self.state {
+            WheelState::Stopped { pair } => Some(pair),
+            _ => None,
+        };
+        self.drawn_names.iter().chain(shown).collect()
+    }
+
+    /// The pairs drawn so far, one per line, for keeping a copy.
+    fn results_text(&self) -> String {
+        self.results()
+            .iter()
+            .map(|pair| format!("{} ==> {}\n", pair.giver.name, pair.receiver.name))
+            .collect()
+    }
+
+    fn reset(&mut self, people: &[Person], fixed_pairs: &[Pair], settings: &SpinSettings) {
         self.state = WheelState::Idle;
-        self.spinner.target = SpinnerTarget::Speed(spinner::IDLE_SPEED);
-        self.spinner.items = people.to_vec();
+        self.spinner.target = SpinnerTarget::idle(settings);
         self.error_message = None;
         self.hat = Hat::with_people(people.into());
+        self.order = people.into();
         self.drawn_names.clear();
+        self.chosen = None;
+        for pair in fixed_pairs {
+            if self.hat.fix_pair(pair) {
+                self.drawn_names.push(pair.clone());
+            }
+        }
+        self.refresh_items();
+        if !self.hat.valid_solution_exists(&valid_pair) {
+            self.error_message = Some(tr!("wheel-impossible").into());
+        }
+    }
+
+    /// Everyone the wheel currently knows about, whether still in the hat or already drawn.
+    pub(crate) fn roster(&self) -> Vec<&Person> {
+        self.hat
+            .givers()
+            .iter()
+            .chain(self.drawn_names.iter().map(|pair| &pair.giver))
+            .chain(self.state.pair().map(|pair| &pair.giver))
+            .collect()
+    }
+
+    fn roster_changes(&self, people: &[Person], fixed_pairs: &[Pair]) -> RosterChanges {
+        let roster = self.roster();
+        let drawn_fixed = self.drawn_names.iter().filter(|pair| pair.fixed);
+        // only people on both lists, so those added or removed don't count as moved
+        let moved = people
+            .iter()
+            .filter(|p| self.order.contains(p))
+            .ne(self.order.iter().filter(|p| people.contains(p)));
+        RosterChanges {
+            reordered: moved,
+            fixed_added: fixed_pairs
+                .iter()
+                .filter(|pair| !self.drawn_names.iter().any(|p| p.same_people(pair)))
+                .cloned()
+                .collect(),
+            fixed_removed: drawn_fixed
+                .filter(|pair| !fixed_pairs.iter().any(|p| p.same_people(pair)))
+                .cloned()
+                .collect(),
+            added: people
+                .iter()
+                .filter(|p| !roster.contains(p))
+                .cloned()
+                .collect(),
+            removed: roster
+                .into_iter()
+                .filter(|p| !people.contains(p))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Brings the hat in line with the people list without clearing unaffected results.
+    ///
+    /// Pairs involving someone who was removed are put back in the hat so the other person
+    /// can be drawn again.
+    fn sync(&mut self, people: &[Person], fixed_pairs: &[Pair]) {
+        match self.state {
+            WheelState::Idle => (),
+            _ => panic!("WheelPage::sync called in wrong state"),
+        }
+        self.refresh_avatars(people);
+        let changes = self.roster_changes(people, fixed_pairs);
+
+        for pair in &changes.fixed_removed {
+            if let Some(idx) = self.drawn_names.iter().position(|p| p == pair) {
+                let mut pair = self.drawn_names.remove(idx);
+                pair.fixed = false;
+                self.hat.put_back(pair);
+            }
+        }
+        for person in &changes.removed {
+            let (affected, kept) = std::mem::take(&mut self.drawn_names)
+                .into_iter()
+                .partition(|pair| &pair.giver == person || &pair.receiver == person);
+            self.drawn_names = kept;
+            for pair in affected {
+                self.hat.put_back(pair);
+            }
+            self.hat.remove_person(person);
+        }
+        for person in changes.added {
+            self.hat.add_person(person);
+        }
+        self.hat.follow_order(people);
+        self.order = people.into();
+        let mut unfixable = Vec::new();
+        for pair in changes.fixed_added {
+            if self.hat.fix_pair(&pair) {
+                self.drawn_names.push(pair);
+            } else {
+                unfixable.push(format!("{} ==> {}", pair.giver.name, pair.receiver.name));
+            }
+        }
+
+        if let Some(person) = &self.chosen {
+            if !self.hat.pool(self.spinning_for).contains(person) {
+                self.chosen = None;
+            }
+        }
+        self.refresh_items();
+        self.error_message = if !unfixable.is_empty() {
+            Some(tr!("wheel-unfixable", pairs = unfixable.join(", ")))
+        } else if !self.hat.valid_solution_exists(&valid_pair) {
+            Some(tr!("wheel-impossible").into())
+        } else {
+            None
+        };
+    }
+
+    /// Picks up pictures changed on the People page since people were put on the wheel.
+    fn refresh_avatars(&mut self, people: &[Person]) {
+        let everyone = self
+            .hat
+            .people_mut()
+            .chain(self.spinner.items.iter_mut())
+            .chain(
+                self.drawn_names
+                    .iter_mut()
+                    .flat_map(|pair| [&mut pair.giver, &mut pair.receiver]),
+            );
+        for person in everyone {
+            if let Some(current) = people.iter().find(|p| *p == person) {
+                if current.avatar != person.avatar {
+                    person.avatar = current.avatar.clone();
+                }
+            }
+        }
+    }
+
+    /// How big each wedge should be, following the wedge size setting.
+    fn wedge_weights(&self, settings: &Settings, time: f32) -> Vec<f32> {
+        // whether `spinner` could land on `landed`, given who is spinning for what
+        let possible = |spinner: &Person, landed: &Person| {
+            spinner != landed
+                && match self.spinning_for {
+                    Role::Giver => valid_pair(spinner, landed),
+                    Role::Receiver => valid_pair(landed, spinner),
+                }
+        };
+        let mut weights = match settings.wedge_sizes {
+            WedgeSizes::Equal => vec![1.0; self.spinner.items.len()],
+            WedgeSizes::ByChances => {
+                let spinners = self.hat.pool(self.spinning_for);
+                self.spinner
+                    .items
+                    .iter()
+                    .map(|landed| {
+                        spinners
+                            .iter()
+                            .filter(|spinner| possible(spinner, landed))
+                            .count() as f32
+                    })
+                    .collect()
+            }
+            WedgeSizes::OnlyPossible => {
+                let current = match (self.state.pair(), self.spin_order) {
+                    (Some(pair), _) => Some(match self.spinning_for {
+                        Role::Giver => &pair.giver,
+                        Role::Receiver => &pair.receiver,
+                    }),
+                    (None, SpinOrder::InOrder) => self.hat.pool(self.spinning_for).first(),
+                    (None, SpinOrder::Chosen) => self.chosen.as_ref(),
+                    // nobody knows who's next until the spin starts
+                    (None, SpinOrder::Random) => None,
+                };
+                match current {
+                    Some(current) => self
+                        .spinner
+                        .items
+                        .iter()
+                        .map(|landed| if possible(current, landed) { 1.0 } else { 0.0 })
+                        .collect(),
+                    None => vec![1.0; self.spinner.items.len()],
+                }
+            }
+        };
+
+        // shrink away the wedges this spin can't land on, then keep them hidden until it's done
+        let shrink = match &self.state {
+            WheelState::Idle => None,
+            WheelState::Narrowing { start_time, .. } => {
+                let progress = (time - start_time) / NARROW_TIME;
+                Some(1.0 - Easing::EaseInOutCubic.apply(progress))
+            }
+            _ => Some(0.0),
+        };
+        if let (Some(shrink), Some(pair), true) =
+            (shrink, self.state.pair(), settings.narrow_before_spin)
+        {
+            let current = match self.spinning_for {
+                Role::Giver => &pair.giver,
+                Role::Receiver => &pair.receiver,
+            };
+            for (weight, landed) in weights.iter_mut().zip(&self.spinner.items) {
+                if !possible(current, landed) {
+                    *weight *= shrink;
+                }
+            }
+        }
+        weights
+    }
+
+    /// Whether there's a result that [`Self::undo`] can take back.
+    fn can_undo(&self) -> bool {
+        match self.state {
+            WheelState::Stopped { .. } => true,
+            WheelState::Idle => self.drawn_names.last().map_or(false, |pair| !pair.fixed),
+            _ => false,
+        }
+    }
+
+    /// Takes back the result being shown, or else the last one recorded, putting both people
+    /// back in the hat so the same person spins again.
+    fn undo(&mut self, settings: &SpinSettings) {
+        if !self.can_undo() {
+            return;
+        }
+        let pair = match std::mem::take(&mut self.state) {
+            WheelState::Stopped { pair } => pair,
+            _ => match self.drawn_names.pop() {
+                Some(pair) => pair,
+                None => return,
+            },
+        };
+        if self.spin_order == SpinOrder::Chosen {
+            self.chosen = Some(match self.spinning_for {
+                Role::Giver => pair.giver.clone(),
+                Role::Receiver => pair.receiver.clone(),
+            });
+        }
+        self.error_message = None;
+        self.hat.put_back_first(pair);
+        self.spinner.target = SpinnerTarget::idle(settings);
+        self.celebration = None;
+        self.refresh_items();
     }
 
-    fn spin(&mut self) {
+    /// Puts everyone who can still be landed on onto the wheel.
+    fn refresh_items(&mut self) {
+        self.spinner.items = self.hat.pool(self.spinning_for.other()).to_vec();
+    }
+
+    fn set_spinning_for(&mut self, role: Role) {
+        match self.state {
+            WheelState::Idle => (),
+            _ => panic!("WheelPage::set_spinning_for called in wrong state"),
+        }
+        self.spinning_for = role;
+        self.chosen = None;
+        self.refresh_items();
+    }
+
+    fn spin(&mut self, settings: &Settings, time: f32) {
         match self.state {
             WheelState::Idle => (),
             _ => panic!("WheelPage::spin called in wrong state"),
         }
         self.error_message = None;
-        match self.hat.draw_name(valid_pair) {
+        let Some(person) = self.next_to_spin() else {
+            self.error_message = Some(match self.spin_order {
+                SpinOrder::Chosen if !self.hat.pool(self.spinning_for).is_empty() => {
+                    tr!("wheel-pick-next", list = self.remaining_heading())
+                }
+                _ => tr!("wheel-no-one-left").into(),
+            });
+            return;
+        };
+        match self.draw_for(&person) {
             Ok(pair) => {
-                self.spinner.target = SpinnerTarget::Speed(spinner::FULL_SPEED);
-                self.state = WheelState::Windup(pair);
+                self.chosen = None;
+                if settings.narrow_before_spin {
+                    self.state = WheelState::Narrowing {
+                        pair,
+                        start_time: time,
+                    };
+                } else {
+                    let spin = &settings.spin;
+                    self.spinner
+                        .ease_to(spin.top_speed, spin.windup_time, spin.easing);
+                    self.state = WheelState::Windup(pair);
+                }
+                self.announce();
+            }
+            Err(DrawError::NotInHat) => {
+                self.chosen = None;
+                self.error_message = Some(tr!("wheel-already-spun", name = person.name));
             }
-            Err(DrawError::NoGivers) => self.error_message = Some("No one left to assign".into()),
             //This case needs to have some 'just draw someone' option
-            Err(DrawError::NoValidReceiver) => {
-                self.error_message = Some("It isn't possible to assign everyone".into())
+            Err(DrawError::NoValidMatch) => {
+                self.error_message = Some(tr!("wheel-impossible").into())
             }
         }
     }
 
-    pub(crate) fn display(&mut self, people: &[Person], ctx: &egui::Context) {
-        egui::SidePanel::left("wheel-left").show(ctx, |ui| side_panel(ui, self));
+    fn draw_for(&mut self, person: &Person) -> Result<Pair, DrawError> {
+        match self.spinning_for {
+            Role::Giver => self.hat.draw_name_for(person, valid_pair),
+            Role::Receiver => self.hat.draw_giver_for(person, valid_pair),
+        }
+    }
 
-        egui::TopBottomPanel::bottom("wheel-bottom").show(ctx, |ui| bottom_panel(ui, self, people));
+    /// Draws everyone left in the hat at once, without spinning the wheel.
+    fn draw_all(&mut self) {
+        match self.state {
+            WheelState::Idle => (),
+            _ => panic!("WheelPage::draw_all called in wrong state"),
+        }
+        self.error_message = None;
+        loop {
+            let next = self
+                .next_to_spin()
+                .or_else(|| self.hat.pool(self.spinning_for).first().cloned());
+            let Some(person) = next else { break };
+            self.chosen = None;
+            match self.draw_for(&person) {
+                Ok(pair) => self.drawn_names.push(pair),
+                Err(_) => {
+                    self.error_message = Some(tr!("wheel-impossible").into());
+                    break;
+                }
+            }
+        }
+        self.refresh_items();
+    }
+
+    /// Skips the rest of the current spin's animation.
+    fn fast_forward(&mut self) {
+        if let Some(pair) = self.state.pair().cloned() {
+            self.spinner.stop_now();
+            self.state = WheelState::Stopped { pair };
+            self.announce();
+        }
+    }
+
+    /// Passes the current status on to screen readers.
+    fn announce(&mut self) {
+        if let Some(status) = self.status() {
+            self.announcement = status;
+        }
+    }
+
+    /// Who the next spin is for: a giver normally, or a receiver when spinning for givers.
+    fn next_to_spin(&self) -> Option<Person> {
+        let remaining = self.hat.pool(self.spinning_for);
+        match self.spin_order {
+            SpinOrder::InOrder => remaining.first().cloned(),
+            SpinOrder::Random => remaining.choose(&mut rand::thread_rng()).cloned(),
+            SpinOrder::Chosen => self.chosen.clone(),
+        }
+    }
+
+    fn remaining_heading(&self) -> &'static str {
+        match self.spinning_for {
+            Role::Giver => tr!("wheel-remaining-givers"),
+            Role::Receiver => tr!("wheel-remaining-receivers"),
+        }
+    }
+
+    /// What's happening with the current spin, or `None` between spins.
+    fn status(&self) -> Option<String> {
+        Some(match (&self.state, self.spinning_for) {
+            (WheelState::Idle, _) => return None,
+            (WheelState::Stopped { pair }, Role::Giver) => {
+                tr!(
+                    "status-giving",
+                    giver = pair.giver.name,
+                    receiver = pair.receiver.name
+                )
+            }
+            (WheelState::Stopped { pair }, Role::Receiver) => {
+                tr!(
+                    "status-buying",
+                    giver = pair.giver.name,
+                    receiver = pair.receiver.name
+                )
+            }
+            (state, Role::Giver) => tr!("status-spinning-for", name = state.pair()?.giver.name),
+            (state, Role::Receiver) => {
+                tr!("status-who-buys", name = state.pair()?.receiver.name)
+            }
+        })
+    }
+
+    pub(crate) fn display(
+        &mut self,
+        people: &[Person],
+        fixed_pairs: &[Pair],
+        settings: &Settings,
+        sound: &mut Sound,
+        ctx: &egui::Context,
+    ) {
+        if confirm::show(ctx, &mut self.confirm_restart).is_some() {
+            self.reset(people, fixed_pairs, &settings.spin);
+        }
+        if self.presenting {
+            if ctx.input().key_pressed(egui::Key::Escape) {
+                self.presenting = false;
+            }
+        } else {
+            let changes = self.roster_changes(people, fixed_pairs);
+            if !changes.is_empty() {
+                egui::TopBottomPanel::top("wheel-sync").show(ctx, |ui| {
+                    sync_panel(ui, self, people, fixed_pairs, &changes)
+                });
+            }
 
+            egui::SidePanel::left("wheel-left").show(ctx, |ui| side_panel(ui, self));
+
+            egui::TopBottomPanel::bottom("wheel-bottom").show(ctx, |ui| {
+                bottom_panel(ui, self, people, fixed_pairs, settings)
+            });
+        }
         egui::CentralPanel::default().show(ctx, |ui| {
-            self.update_animation(ui);
-            self.spinner.render(ui);
+            if self.presenting {
+                self.presentation_header(ui, &settings.shortcuts);
+            }
+            self.update_animation(ui, settings, sound);
+            let time = ui.input().time as f32;
+            self.spinner.weights = self.wedge_weights(settings, time);
+            let wheel = self.spinner.render(ui, settings);
+            live_region(ui.ctx(), &self.announcement);
+            if let Some(celebration) = &self.celebration {
+                ui.painter().extend(celebration.shapes(ui, wheel.rect));
+            }
+            // thin wedges come and go while narrowing, so wait for it to finish
+            let narrowing = matches!(self.state, WheelState::Narrowing { .. });
+            if !wheel.legend.is_empty() && !narrowing {
+                legend_window(ui.ctx(), wheel.rect, &wheel.legend);
+            }
+        });
+        if !self.presenting {
+            export::calendar_window(ctx, self);
+            export::print_window(ctx, self);
+        }
+    }
+
+    /// Whether there's anyone left to spin for and it's settled who is next, as the Spin button
+    /// needs.
+    fn can_spin(&self) -> bool {
+        !self.hat.pool(self.spinning_for).is_empty()
+            && (self.spin_order != SpinOrder::Chosen || self.chosen.is_some())
+    }
+
+    /// Runs a keyboard shortcut meant for the wheel.
+    pub(crate) fn run_shortcut(
+        &mut self,
+        action: Action,
+        people: &[Person],
+        fixed_pairs: &[Pair],
+        settings: &Settings,
+        time: f32,
+    ) {
+        match (action, &self.state) {
+            (Action::Spin, WheelState::Idle) if self.can_spin() => self.spin(settings, time),
+            (Action::NextSpin, WheelState::Stopped { pair }) => {
+                let pair = pair.clone();
+                self.add_result(pair, &settings.spin);
+            }
+            (Action::Undo, _) => self.undo(&settings.spin),
+            (Action::Restart, _) => self.request_restart(people, fixed_pairs, &settings.spin),
+            (Action::Present, _) => self.presenting = !self.presenting,
+            _ => (),
+        }
+    }
+
+    /// Who the wheel is spinning for, in letters big enough to read on a projector.
+    fn presentation_header(&mut self, ui: &mut egui::Ui, shortcuts: &Shortcuts) {
+        let ctx = ui.ctx().clone();
+        let (headline, hint) = match self.status() {
+            Some(status) => {
+                let hint = match self.state {
+                    WheelState::Stopped { .. } => tr!(
+                        "present-next-hint",
+                        shortcut = shortcuts.describe(Action::NextSpin, &ctx)
+                    ),
+                    _ => String::new(),
+                };
+                (status, hint)
+            }
+            None if self.hat.pool(self.spinning_for).is_empty() => {
+                (tr!("wheel-all-drawn").to_owned(), String::new())
+            }
+            None => {
+                let next = match self.spin_order {
+                    SpinOrder::InOrder => self.hat.pool(self.spinning_for).first(),
+                    SpinOrder::Chosen => self.chosen.as_ref(),
+                    SpinOrder::Random => None,
+                };
+                let headline = match next {
+                    Some(person) => tr!("present-up-next", name = person.name),
+                    None => tr!("present-whos-next").to_owned(),
+                };
+                let hint = tr!(
+                    "present-spin-hint",
+                    shortcut = shortcuts.describe(Action::Spin, &ctx)
+                );
+                (headline, hint)
+            }
+        };
+
+        let size = (ui.available_height() * 0.07).max(24.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(headline).size(size).strong());
+            ui.horizontal(|ui| {
+                ui.weak(hint);
+                if let Some(msg) = &self.error_message {
+                    ui.colored_label(Color32::RED, msg);
+                }
+                if ui.small_button(tr!("present-exit")).clicked() {
+                    self.presenting = false;
+                }
+            });
         });
     }
 
-    fn update_animation(&mut self, ui: &egui::Ui) {
-        let delta_time = ui.input().stable_dt.min(0.1);
-        let time = ui.input().time as f32;
+    fn update_animation(&mut self, ui: &egui::Ui, settings: &Settings, sound: &mut Sound) {
+        let was_stopped = matches!(self.state, WheelState::Stopped { .. });
+
+        if animate(ui, &mut self.state, &mut self.spinner, &settings.spin) {
+            self.announce();
+        }
 
-        self.state.try_transition(&mut self.spinner, time);
+        let just_stopped = !was_stopped && matches!(self.state, WheelState::Stopped { .. });
+        if let WheelState::Stopped { pair } = &self.state {
+            if just_stopped && !settings.reduce_motion {
+                let landed_on = match self.spinning_for {
+                    Role::Giver => &pair.receiver,
+                    Role::Receiver => &pair.giver,
+                };
+                let seed = self.drawn_names.len() as u64;
+                self.celebration = Some(Celebration::new(
+                    &landed_on.name,
+                    seed,
+                    settings.wheel_colors(),
+                ));
+            }
+        } else {
+            self.celebration = None;
+        }
+        if let Some(celebration) = &mut self.celebration {
+            celebration.step(ui.input().stable_dt.min(0.1));
+            if celebration.is_animating() {
+                ui.ctx().request_repaint();
+            }
+        }
 
-        self.spinner.step_animation(delta_time);
+        let ticked = self.spinner.take_tick();
+        let spinning = matches!(
+            self.state,
+            WheelState::Windup(_)
+                | WheelState::HoldAtTopSpeed { .. }
+                | WheelState::SlowToStop { .. }
+        );
+        let started_spinning = spinning && !self.was_spinning;
+        self.was_spinning = spinning;
+        if settings.muted {
+            return;
+        }
+        if started_spinning {
+            sound.play(Effect::Whoosh);
+        }
+        match self.state {
+            WheelState::Idle | WheelState::Narrowing { .. } => (),
+            WheelState::Stopped { .. } => {
+                if just_stopped {
+                    sound.play(Effect::Fanfare);
+                }
+            }
+            _ => {
+                if ticked {
+                    sound.play(Effect::Tick);
+                }
+            }
+        }
     }
 
-    fn add_result(&mut self, pair: Pair) {
-        self.spinner.items.retain(|p| p != &pair.receiver);
+    fn add_result(&mut self, pair: Pair, settings: &SpinSettings) {
+        let landed_on = match self.spinning_for {
+            Role::Giver => &pair.receiver,
+            Role::Receiver => &pair.giver,
+        };
+        self.spinner.items.retain(|p| p != landed_on);
 
         self.drawn_names.push(pair);
 
-        self.spinner.target = SpinnerTarget::Speed(spinner::IDLE_SPEED);
+        self.spinner.target = SpinnerTarget::idle(settings);
         self.state = WheelState::Idle;
     }
 }
 
+/// Steps the spin along by a frame. Returns whether the wheel just landed on its result.
+fn animate(
+    ui: &egui::Ui,
+    state: &mut WheelState,
+    spinner: &mut Spinner,
+    settings: &SpinSettings,
+) -> bool {
+    let delta_time = ui.input().stable_dt.min(0.1);
+    let time = ui.input().time as f32;
+
+    if let WheelState::Idle = state {
+        // picks up changes to the idle drift setting, and lets the wheel come to rest when no one
+        // is using it
+        spinner.target = spinner.idle_target(ui, settings);
+    }
+    let landed = state.try_transition(spinner, time, settings);
+
+    spinner.step_animation(delta_time);
+
+    // a wheel left sitting still doesn't need redrawing until something else changes, but a spin
+    // that has just come to rest still has to move on to showing the result
+    let settled = matches!(state, WheelState::Idle | WheelState::Stopped { .. });
+    if !(settled && spinner.at_rest()) {
+        ui.ctx().request_repaint();
+    }
+    landed
+}
+
+/// Where screen readers hear about spins starting and stopping. Changing the text is what gets
+/// it read out, so it keeps the last announcement until there's a new one.
+fn live_region(ctx: &egui::Context, announcement: &str) {
+    if let Some(mut node) = ctx.accesskit_node(egui::Id::new("wheel-announcements")) {
+        node.role = egui::accesskit::Role::Status;
+        node.live = Some(egui::accesskit::Live::Polite);
+        node.name = Some(announcement.into());
+    }
+}
+
+/// A small wheel on the settings page for trying out spin timing.
+#[derive(serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub(crate) struct SpinPreview {
+    state: WheelState,
+    spinner: Spinner,
+}
+
+impl Default for SpinPreview {
+    fn default() -> Self {
+        let mut spinner = Spinner::default();
+        spinner.items = ["Alice", "Bob", "Carol", "Dave", "Erin", "Frank"]
+            .into_iter()
+            .map(|name| Person::new(name, ""))
+            .collect();
+        Self {
+            state: WheelState::Idle,
+            spinner,
+        }
+    }
+}
+
+impl SpinPreview {
+    pub(crate) fn show(&mut self, ui: &mut egui::Ui, settings: &Settings) {
+        let spin = &settings.spin;
+        match self.state {
+            WheelState::Idle | WheelState::Stopped { .. } => {
+                if ui.button(tr!("preview-spin")).clicked() {
+                    let placeholder = self.spinner.items[0].clone();
+                    self.spinner
+                        .ease_to(spin.top_speed, spin.windup_time, spin.easing);
+                    self.state = WheelState::Windup(Pair {
+                        giver: placeholder.clone(),
+                        receiver: placeholder,
+                        fixed: false,
+                    });
+                }
+            }
+            _ => {
+                if ui.button(tr!("preview-stop")).clicked() {
+                    self.state = WheelState::Idle;
+                    self.spinner.target = SpinnerTarget::idle(spin);
+                }
+            }
+        }
+        animate(ui, &mut self.state, &mut self.spinner, spin);
+
+        ui.allocate_ui(egui::vec2(240.0, 240.0), |ui| {
+            self.spinner.render(ui, settings);
+        });
+    }
+}
+
+/// Lists what the initials or numbers on wedges too thin for a name stand for.
+fn legend_window(ctx: &egui::Context, wheel_rect: egui::Rect, legend: &[(String, String)]) {
+    egui::Window::new(tr!("wheel-legend"))
+        .id(egui::Id::new("wheel-legend-window"))
+        .default_pos(wheel_rect.right_top() + egui::vec2(8.0, 0.0))
+        .resizable(false)
+        .vscroll(true)
+        .show(ctx, |ui| {
+            egui::Grid::new("wheel-legend")
+                .num_columns(2)
+                .striped(true)
+                .show(ui, |ui| {
+                    for (short, name) in legend {
+                        ui.strong(short);
+                        ui.label(name);
+                        ui.end_row();
+                    }
+                });
+        });
+}
+
 fn side_panel(ui: &mut egui::Ui, wheel: &mut WheelPage) {
     egui::ScrollArea::vertical().show(ui, |ui| {
-        ui.heading("Remaining Givers");
-        for person in wheel.hat.givers().iter() {
-            ui.person(person);
+        egui::ComboBox::from_label(tr!("wheel-spin-order"))
+            .selected_text(wheel.spin_order.label())
+            .show_ui(ui, |ui| {
+                for order in [SpinOrder::InOrder, SpinOrder::Random, SpinOrder::Chosen] {
+                    ui.selectable_value(&mut wheel.spin_order, order, order.label());
+                }
+            });
+        let idle = matches!(wheel.state, WheelState::Idle);
+        ui.add_enabled_ui(idle, |ui| {
+            let mut spinning_for = wheel.spinning_for;
+            egui::ComboBox::from_label(tr!("wheel-spin-to-find"))
+                .selected_text(spinning_for_label(spinning_for))
+                .show_ui(ui, |ui| {
+                    for role in [Role::Giver, Role::Receiver] {
+                        ui.selectable_value(&mut spinning_for, role, spinning_for_label(role));
+                    }
+                });
+            if spinning_for != wheel.spinning_for {
+                wheel.set_spinning_for(spinning_for);
+            }
+        });
+        ui.heading(wheel.remaining_heading());
+        if wheel.spin_order == SpinOrder::Chosen {
+            for person in wheel.hat.pool(wheel.spinning_for).iter() {
+                let selected = wheel.chosen.as_ref() == Some(person);
+                let text = format!("{} - {}", person.name, person.group);
+                if ui.selectable_label(selected, text).clicked() {
+                    wheel.chosen = Some(person.clone());
+                }
+            }
+        } else {
+            for person in wheel.hat.pool(wheel.spinning_for).iter() {
+                ui.person(person);
+            }
         }
         ui.separator();
-        ui.heading("Results");
+        ui.horizontal(|ui| {
+            ui.heading(tr!("wheel-results"));
+            if ui.button(tr!("calendar-open")).clicked() {
+                wheel.calendar_open = true;
+            }
+            if ui.button(tr!("print-open")).clicked() {
+                wheel.print_open = true;
+            }
+        });
         for pair in &wheel.drawn_names {
             ui.horizontal(|ui| {
                 ui.person(&pair.giver);
                 ui.label("==>");
                 ui.person(&pair.receiver);
+                if pair.fixed {
+                    ui.label(RichText::new(tr!("wheel-fixed")).italics());
+                }
             });
         }
     });
 }
 
-fn bottom_panel(ui: &mut egui::Ui, wheel: &mut WheelPage, people: &[Person]) {
+fn spinning_for_label(role: Role) -> &'static str {
+    match role {
+        Role::Giver => tr!("find-receivers"),
+        Role::Receiver => tr!("find-givers"),
+    }
+}
+
+fn sync_panel(
+    ui: &mut egui::Ui,
+    wheel: &mut WheelPage,
+    people: &[Person],
+    fixed_pairs: &[Pair],
+    changes: &RosterChanges,
+) {
+    if changes.changed_people() {
+        ui.colored_label(
+            Color32::from_rgb(200, 120, 0),
+            tr!(
+                "sync-changed",
+                added = changes.added.len(),
+                removed = changes.removed.len()
+            ),
+        );
+    }
+    if changes.reordered {
+        ui.colored_label(Color32::from_rgb(200, 120, 0), tr!("sync-reordered"));
+    }
+    for person in &changes.added {
+        ui.horizontal(|ui| {
+            ui.label("+");
+            ui.person(person);
+        });
+    }
+    for person in &changes.removed {
+        ui.horizontal(|ui| {
+            ui.label("-");
+            ui.person(person);
+        });
+    }
+    for (sign, pair) in changes
+        .fixed_added
+        .iter()
+        .map(|pair| ("+", pair))
+        .chain(changes.fixed_removed.iter().map(|pair| ("-", pair)))
+    {
+        ui.horizontal(|ui| {
+            ui.label(tr!("sync-fixed-pair", sign = sign));
+            ui.person(&pair.giver);
+            ui.label("==>");
+            ui.person(&pair.receiver);
+        });
+    }
+    let idle = matches!(wheel.state, WheelState::Idle);
+    ui.horizontal(|ui| {
+        if ui
+            .add_enabled(idle, egui::Button::new(tr!("sync-update")))
+            .on_hover_text(tr!("sync-update-hint"))
+            .on_disabled_hover_text(tr!("sync-wait"))
+            .clicked()
+        {
+            wheel.sync(people, fixed_pairs);
+        }
+        ui.label(tr!("sync-or-restart"));
+    });
+}
+
+fn bottom_panel(
+    ui: &mut egui::Ui,
+    wheel: &mut WheelPage,
+    people: &[Person],
+    fixed_pairs: &[Pair],
+    settings: &Settings,
+) {
+    let spin = &settings.spin;
     match &wheel.state {
         WheelState::Idle => {
-            if !wheel.hat.givers().is_empty() {
-                if ui.button(RichText::new("Spin Wheel").heading()).clicked() {
-                    wheel.spin();
+            if !wheel.hat.pool(wheel.spinning_for).is_empty() {
+                let can_spin = wheel.can_spin();
+                let button = egui::Button::new(RichText::new(tr!("wheel-spin")).heading());
+                let hint = tr!("wheel-pick-next", list = wheel.remaining_heading());
+                if ui
+                    .add_enabled(can_spin, button)
+                    .on_disabled_hover_text(hint)
+                    .clicked()
+                {
+                    wheel.spin(settings, ui.input().time as f32);
+                }
+                if ui
+                    .button(tr!("wheel-draw-all"))
+                    .on_hover_text(tr!("wheel-draw-all-hint"))
+                    .clicked()
+                {
+                    wheel.draw_all();
                 }
             } else {
-                ui.label(RichText::new("All names drawn").heading());
+                ui.label(RichText::new(tr!("wheel-all-drawn")).heading());
             }
         }
-        WheelState::Windup(pair)
-        | WheelState::HoldAtTopSpeed { pair, .. }
-        | WheelState::SlowToStop { pair, .. } => {
-            ui.label(RichText::new(format!("Spinning for {}", pair.giver.name)).heading());
-        }
         WheelState::Stopped { pair } => {
             let pair = pair.clone();
-            ui.horizontal(|ui| {
-                ui.heading(format!(
-                    "{} is giving to {}",
-                    pair.giver.name, pair.receiver.name
-                ));
-                if ui.button(RichText::new("Next Spin").heading()).clicked() {
-                    wheel.add_result(pair)
+            i18n::horizontal(ui, |ui| {
+                ui.heading(wheel.status().unwrap_or_default());
+                if ui
+                    .button(RichText::new(tr!("wheel-next-spin")).heading())
+                    .clicked()
+                {
+                    wheel.add_result(pair, spin)
+                }
+            });
+        }
+        _ => {
+            i18n::horizontal(ui, |ui| {
+                ui.heading(wheel.status().unwrap_or_default());
+                if ui.button(tr!("wheel-fast-forward")).clicked() {
+                    wheel.fast_forward();
                 }
             });
         }
@@ -182,7 +1148,298 @@ fn bottom_panel(ui: &mut egui::Ui, wheel: &mut WheelPage, people: &[Person]) {
         ui.colored_label(Color32::RED, msg);
     }
 
-    if ui.button("Restart").clicked() {
-        wheel.reset(people);
+    i18n::horizontal(ui, |ui| {
+        if ui.button(tr!("wheel-restart")).clicked() {
+            wheel.request_restart(people, fixed_pairs, spin);
+        }
+        if ui
+            .add_enabled(wheel.can_undo(), egui::Button::new(tr!("wheel-undo")))
+            .on_hover_text(tr!("wheel-undo-hint"))
+            .clicked()
+        {
+            wheel.undo(spin);
+        }
+        if ui
+            .button(tr!("wheel-present"))
+            .on_hover_text(tr!(
+                "wheel-present-hint",
+                spin = settings.shortcuts.describe(Action::Spin, ui.ctx()),
+                next = settings.shortcuts.describe(Action::NextSpin, ui.ctx()),
+            ))
+            .clicked()
+        {
+            wheel.presenting = true;
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::accesskit;
+
+    use super::spinner::{IDLE_DRIFT_TIMEOUT, IDLE_SPEED};
+    use super::*;
+
+    /// With one person in each group, Alice can only give to Bob and Bob only to Alice.
+    fn people() -> Vec<Person> {
+        vec![Person::new("Alice", "Smith"), Person::new("Bob", "Jones")]
+    }
+
+    fn settings() -> Settings {
+        Settings {
+            muted: true,
+            reduce_motion: true,
+            narrow_before_spin: false,
+            spin: SpinSettings {
+                windup_time: 0.2,
+                spin_time: 0.2,
+                stop_time: 0.2,
+                ..SpinSettings::default()
+            },
+            ..Settings::default()
+        }
+    }
+
+    fn wheel(spinning_for: Role) -> WheelPage {
+        let mut wheel = WheelPage::default();
+        wheel.reset(&people(), &[], &settings().spin);
+        wheel.set_spinning_for(spinning_for);
+        wheel
+    }
+
+    #[test]
+    fn sync_keeps_results_not_involving_removed_people() {
+        let mut people: Vec<_> = ["Alice", "Bob", "Carol", "Dave"]
+            .into_iter()
+            .map(|name| Person::new(name, name))
+            .collect();
+        let mut wheel = WheelPage::default();
+        wheel.reset(&people, &[], &settings().spin);
+        for (giver, receiver) in [(0, 1), (2, 3)] {
+            let pair = Pair {
+                giver: people[giver].clone(),
+                receiver: people[receiver].clone(),
+                fixed: false,
+            };
+            assert!(wheel.hat.fix_pair(&pair));
+            wheel.drawn_names.push(pair);
+        }
+
+        let bob = people.remove(1);
+        let erin = Person::new("Erin", "Erin");
+        people.push(erin.clone());
+        let changes = wheel.roster_changes(&people, &[]);
+        assert_eq!(changes.added.len(), 1);
+        assert_eq!(changes.added[0], erin);
+        assert_eq!(changes.removed.len(), 1);
+        assert_eq!(changes.removed[0], bob);
+
+        wheel.sync(&people, &[]);
+        assert!(wheel.roster_changes(&people, &[]).is_empty());
+        // Carol's result stands, Alice goes back in the hat and Bob is gone for good
+        assert_eq!(wheel.drawn_names.len(), 1);
+        assert_eq!(wheel.drawn_names[0].giver.name, "Carol");
+        assert_eq!(
+            wheel.hat.givers(),
+            [people[0].clone(), people[2].clone(), erin.clone()]
+        );
+        assert_eq!(
+            wheel.hat.pool(Role::Receiver),
+            [people[0].clone(), people[1].clone(), erin]
+        );
+        assert!(!wheel.roster().contains(&&bob));
+    }
+
+    #[test]
+    fn follows_people_moved_after_the_draw_started() {
+        let mut people = people();
+        people.push(Person::new("Carol", "Lee"));
+        let mut wheel = WheelPage::default();
+        wheel.reset(&people, &[], &settings().spin);
+        assert!(wheel.roster_changes(&people, &[]).is_empty());
+
+        people.swap(0, 2);
+        let mut added = people.clone();
+        added.push(Person::new("Dave", "Lee"));
+        let changes = wheel.roster_changes(&added, &[]);
+        assert!(changes.reordered);
+        assert_eq!(changes.added, [Person::new("Dave", "Lee")]);
+
+        wheel.sync(&added, &[]);
+        assert!(wheel.roster_changes(&added, &[]).is_empty());
+        assert_eq!(wheel.hat.givers(), &added[..]);
+        assert_eq!(wheel.hat.pool(Role::Receiver), &added[..]);
+
+        // moving someone in or out alone isn't a reorder
+        let changes = wheel.roster_changes(&added[1..], &[]);
+        assert!(!changes.reordered);
+        assert_eq!(changes.removed.len(), 1);
+    }
+
+    /// Checks the status and what screen readers were told match `expected`.
+    fn assert_status(wheel: &WheelPage, expected: &str) {
+        assert_eq!(wheel.status().as_deref(), Some(expected));
+        assert_eq!(wheel.announcement, expected);
+    }
+
+    #[test]
+    fn announces_spin_for_receivers() {
+        let mut wheel = wheel(Role::Giver);
+        assert_eq!(wheel.status(), None);
+        assert_eq!(wheel.announcement, "");
+
+        wheel.spin(&settings(), 0.0);
+        assert!(matches!(wheel.state, WheelState::Windup(_)));
+        assert_status(&wheel, &tr!("status-spinning-for", name = "Alice"));
+
+        wheel.fast_forward();
+        assert!(matches!(wheel.state, WheelState::Stopped { .. }));
+        assert_status(
+            &wheel,
+            &tr!("status-giving", giver = "Alice", receiver = "Bob"),
+        );
+    }
+
+    #[test]
+    fn announces_spin_for_givers() {
+        let mut wheel = wheel(Role::Receiver);
+
+        wheel.spin(&settings(), 0.0);
+        assert!(matches!(wheel.state, WheelState::Windup(_)));
+        assert_status(&wheel, &tr!("status-who-buys", name = "Alice"));
+
+        wheel.fast_forward();
+        assert_status(
+            &wheel,
+            &tr!("status-buying", giver = "Bob", receiver = "Alice"),
+        );
+    }
+
+    #[test]
+    fn announcement_kept_between_spins() {
+        let mut wheel = wheel(Role::Giver);
+        wheel.spin(&settings(), 0.0);
+        wheel.fast_forward();
+        if let WheelState::Stopped { pair } = &wheel.state {
+            wheel.add_result(pair.clone(), &settings().spin);
+        }
+        assert_eq!(wheel.status(), None);
+        assert_eq!(
+            wheel.announcement,
+            tr!("status-giving", giver = "Alice", receiver = "Bob")
+        );
+    }
+
+    /// Spins the wheel in a headless context until it lands, and returns the accessibility tree
+    /// from the last frame.
+    fn spin_to_a_stop(wheel: &mut WheelPage) -> Vec<accesskit::Node> {
+        let ctx = egui::Context::default();
+        ctx.enable_accesskit();
+        let (people, settings) = (people(), settings());
+        let mut sound = Sound::default();
+        wheel.spin(&settings, 0.0);
+
+        let mut time = 0.0;
+        loop {
+            let input = egui::RawInput {
+                time: Some(time),
+                predicted_dt: 0.1,
+                screen_rect: Some(egui::Rect::from_min_size(
+                    egui::Pos2::ZERO,
+                    egui::vec2(800.0, 600.0),
+                )),
+                ..Default::default()
+            };
+            let output = ctx.run(input, |ctx| {
+                wheel.display(&people, &[], &settings, &mut sound, ctx)
+            });
+            time += 0.1;
+            if matches!(wheel.state, WheelState::Stopped { .. }) || time > 10.0 {
+                let update = output.platform_output.accesskit_update.expect("enabled");
+                return update
+                    .nodes
+                    .into_iter()
+                    .map(|(_, node)| (*node).clone())
+                    .collect();
+            }
+        }
+    }
+
+    #[test]
+    fn restart_counts_leave_out_fixed_pairs() {
+        let people: Vec<_> = ["Alice", "Bob", "Carol", "Dave"]
+            .into_iter()
+            .map(|name| Person::new(name, name))
+            .collect();
+        let fixed = [Pair::fixed(people[0].clone(), people[1].clone())];
+        let mut wheel = WheelPage::default();
+        wheel.reset(&people, &fixed, &settings().spin);
+        assert_eq!(wheel.drawn_of_total(), (0, 3));
+
+        let pair = wheel.hat.draw_name_for(&people[2], valid_pair).unwrap();
+        wheel.drawn_names.push(pair);
+        assert_eq!(wheel.drawn_of_total(), (1, 3));
+    }
+
+    #[test]
+    fn spin_shortcut_waits_like_the_button() {
+        let mut wheel = wheel(Role::Receiver);
+        wheel.spin_order = SpinOrder::Chosen;
+        wheel.run_shortcut(Action::Spin, &people(), &[], &settings(), 0.0);
+        assert!(matches!(wheel.state, WheelState::Idle));
+
+        wheel.chosen = Some(people()[1].clone());
+        wheel.run_shortcut(Action::Spin, &people(), &[], &settings(), 0.0);
+        assert!(matches!(wheel.state, WheelState::Windup(_)));
+    }
+
+    #[test]
+    fn idle_drift_stops_when_no_one_is_using_the_wheel() {
+        let ctx = egui::Context::default();
+        let mut spinner = Spinner::default();
+        let spin = SpinSettings::default();
+        let mut idle_speed = |time: f64, has_focus: bool| {
+            let input = egui::RawInput {
+                time: Some(time),
+                has_focus,
+                ..Default::default()
+            };
+            let mut target = None;
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default()
+                    .show(ctx, |ui| target = Some(spinner.idle_target(ui, &spin)));
+            });
+            match target {
+                Some(SpinnerTarget::Speed(speed)) => speed,
+                other => panic!("{other:?}"),
+            }
+        };
+
+        assert_eq!(idle_speed(1.0, true), IDLE_SPEED);
+        assert_eq!(idle_speed(2.0, false), 0.0);
+        assert_eq!(idle_speed(f64::from(IDLE_DRIFT_TIMEOUT) + 1.0, true), 0.0);
+    }
+
+    #[test]
+    fn screen_readers_see_the_wheel_and_hear_the_result() {
+        let mut wheel = wheel(Role::Giver);
+        let nodes = spin_to_a_stop(&mut wheel);
+        assert!(matches!(wheel.state, WheelState::Stopped { .. }));
+
+        let image = nodes
+            .iter()
+            .find(|node| node.role == accesskit::Role::Image)
+            .expect("the wheel is described");
+        let name = image.name.as_deref().unwrap_or_default();
+        assert!(name.contains("Bob"), "{name}");
+
+        let expected = tr!("status-giving", giver = "Alice", receiver = "Bob");
+        assert_eq!(wheel.announcement, expected);
+        let live = nodes
+            .iter()
+            .find(|node| node.live == Some(accesskit::Live::Polite))
+            .expect("there is a live region");
+        assert_eq!(live.role, accesskit::Role::Status);
+        assert_eq!(live.name.as_deref(), Some(expected.as_str()));
     }
 }
@@ -0,0 +1,180 @@
+use std::f32::consts::TAU;
+
+use egui::{Color32, FontId, Pos2, Rect, Rounding, Shape, Stroke, Vec2};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const PARTICLE_COUNT: usize = 120;
+/// Seconds until the last of the confetti has faded out.
+const LIFETIME: f32 = 3.0;
+/// Seconds the name card takes to grow to full size.
+const CARD_GROW_TIME: f32 = 0.4;
+const GRAVITY: f32 = 1.5;
+const DRAG: f32 = 1.2;
+
+/// Confetti bursting out of the middle of the wheel, with a card naming who the wheel stopped on.
+///
+/// Everything is laid out in wheel space, where the wheel spans -1..=1 on both axes, and only
+/// advances through [`Celebration::step`], so the same seed and frame times always give the same
+/// picture.
+pub(crate) struct Celebration {
+    name: String,
+    particles: Vec<Particle>,
+    age: f32,
+}
+
+struct Particle {
+    pos: Vec2,
+    vel: Vec2,
+    angle: f32,
+    spin: f32,
+    size: Vec2,
+    color: Color32,
+}
+
+impl Celebration {
+    pub(crate) fn new(name: &str, seed: u64, colors: &[Color32]) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let particles = (0..PARTICLE_COUNT)
+            .map(|idx| {
+                let direction = rng.gen_range(0.0..TAU);
+                let speed = rng.gen_range(0.8..2.2);
+                Particle {
+                    pos: Vec2::ZERO,
+                    // biased upwards so the burst arcs before falling
+                    vel: Vec2::angled(direction) * speed - Vec2::new(0.0, 1.0),
+                    angle: rng.gen_range(0.0..TAU),
+                    spin: rng.gen_range(-8.0..8.0),
+                    size: Vec2::new(rng.gen_range(0.02..0.04), rng.gen_range(0.01..0.02)),
+                    color: colors
+                        .get(idx % colors.len().max(1))
+                        .copied()
+                        .unwrap_or(Color32::GOLD),
+                }
+            })
+            .collect();
+
+        Self {
+            name: name.to_owned(),
+            particles,
+            age: 0.0,
+        }
+    }
+
+    pub(crate) fn step(&mut self, delta_time: f32) {
+        self.age += delta_time;
+        let drag = (-DRAG * delta_time).exp();
+        for particle in &mut self.particles {
+            particle.vel = particle.vel * drag + Vec2::new(0.0, GRAVITY * delta_time);
+            particle.pos += particle.vel * delta_time;
+            particle.angle += particle.spin * delta_time;
+        }
+    }
+
+    /// Whether there's still something moving, so the screen needs redrawing.
+    pub(crate) fn is_animating(&self) -> bool {
+        self.age < LIFETIME
+    }
+
+    pub(crate) fn shapes(&self, ui: &egui::Ui, rect: Rect) -> Vec<Shape> {
+        let center = rect.center();
+        let scale = rect.width().min(rect.height()) / 2.0;
+        let to_screen = |v: Vec2| center + v * scale;
+
+        let mut shapes = Vec::with_capacity(self.particles.len() + 2);
+
+        let fade = 1.0 - (self.age / LIFETIME).clamp(0.0, 1.0);
+        if fade > 0.0 {
+            for particle in &self.particles {
+                let rot = egui::emath::Rot2::from_angle(particle.angle);
+                let half = particle.size / 2.0;
+                let corners = [
+                    Vec2::new(-half.x, -half.y),
+                    Vec2::new(half.x, -half.y),
+                    Vec2::new(half.x, half.y),
+                    Vec2::new(-half.x, half.y),
+                ];
+                let points = corners
+                    .iter()
+                    .map(|&corner| to_screen(particle.pos + rot * corner))
+                    .collect();
+                let color = particle.color.linear_multiply(fade);
+                shapes.push(Shape::convex_polygon(points, color, Stroke::NONE));
+            }
+        }
+
+        let grow = (self.age / CARD_GROW_TIME).clamp(0.0, 1.0);
+        let card_scale = ease_out_back(grow);
+        if card_scale > 0.0 {
+            let font_id = FontId::proportional(scale * 0.15 * card_scale);
+            let text_color = ui.visuals().strong_text_color();
+            let galley = ui
+                .fonts()
+                .layout_no_wrap(self.name.clone(), font_id, text_color);
+            let padding = Vec2::splat(scale * 0.05 * card_scale);
+            let card = Rect::from_center_size(center, galley.size() + padding * 2.0);
+
+            shapes.push(Shape::rect_filled(
+                card,
+                Rounding::same(scale * 0.03 * card_scale),
+                ui.visuals().extreme_bg_color,
+            ));
+            shapes.push(Shape::rect_stroke(
+                card,
+                Rounding::same(scale * 0.03 * card_scale),
+                Stroke::new(2.0, ui.visuals().selection.bg_fill),
+            ));
+            let text_pos: Pos2 = card.min + padding;
+            shapes.push(Shape::galley(text_pos, galley));
+        }
+
+        shapes
+    }
+}
+
+/// Overshoots slightly before settling, so the name card pops in.
+fn ease_out_back(t: f32) -> f32 {
+    const C1: f32 = 1.70158;
+    const C3: f32 = C1 + 1.0;
+    1.0 + C3 * (t - 1.0).powi(3) + C1 * (t - 1.0).powi(2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COLORS: [Color32; 2] = [Color32::RED, Color32::GREEN];
+    const FRAMES: [f32; 5] = [1.0 / 60.0, 1.0 / 30.0, 0.05, 1.0 / 60.0, 0.1];
+
+    fn positions(seed: u64) -> Vec<Vec2> {
+        let mut celebration = Celebration::new("Alice", seed, &COLORS);
+        for delta_time in FRAMES {
+            celebration.step(delta_time);
+        }
+        celebration
+            .particles
+            .iter()
+            .map(|particle| particle.pos)
+            .collect()
+    }
+
+    #[test]
+    fn same_seed_same_frames_same_picture() {
+        assert_eq!(positions(7), positions(7));
+    }
+
+    #[test]
+    fn different_seeds_differ() {
+        assert_ne!(positions(7), positions(8));
+    }
+
+    #[test]
+    fn stops_animating_after_lifetime() {
+        let mut celebration = Celebration::new("Alice", 1, &COLORS);
+        assert!(celebration.is_animating());
+        celebration.step(LIFETIME / 2.0);
+        assert!(celebration.is_animating());
+        celebration.step(LIFETIME / 2.0 + 0.01);
+        assert!(!celebration.is_animating());
+    }
+}
@@ -0,0 +1,182 @@
+//! Windows for taking the results somewhere else.
+
+use egui::Color32;
+
+use crate::calendar::{self, Date};
+use crate::print;
+use crate::save;
+
+use super::WheelPage;
+
+const DEFAULT_CALENDAR_PATH: &str = "gift-exchange.ics";
+const DEFAULT_PRINT_PATH: &str = "gift-exchange.html";
+
+/// Details about the exchange, and saving them with a reminder for each giver as a calendar file.
+pub(super) fn calendar_window(ctx: &egui::Context, wheel: &mut WheelPage) {
+    let mut open = wheel.calendar_open;
+    egui::Window::new(tr!("calendar-title"))
+        .id(egui::Id::new("calendar-window"))
+        .open(&mut open)
+        .collapsible(false)
+        .default_width(360.0)
+        .show(ctx, |ui| {
+            let event = &mut wheel.event;
+            let date = Date::parse(&event.date);
+            egui::Grid::new("calendar-event")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    ui.label(tr!("calendar-name"));
+                    ui.add(
+                        egui::TextEdit::singleline(&mut event.name)
+                            .hint_text(tr!("calendar-default-name")),
+                    );
+                    ui.end_row();
+                    ui.label(tr!("calendar-date"));
+                    ui.add(egui::TextEdit::singleline(&mut event.date).hint_text("YYYY-MM-DD"));
+                    ui.end_row();
+                    ui.label(tr!("calendar-location"));
+                    ui.text_edit_singleline(&mut event.location);
+                    ui.end_row();
+                    ui.label(tr!("calendar-notes"));
+                    ui.add(
+                        egui::TextEdit::multiline(&mut event.notes)
+                            .hint_text(tr!("calendar-notes-hint"))
+                            .desired_rows(2),
+                    );
+                    ui.end_row();
+                });
+            if date.is_none() && !event.date.trim().is_empty() {
+                ui.colored_label(Color32::RED, tr!("calendar-bad-date"));
+            }
+
+            ui.separator();
+            ui.checkbox(&mut event.reminders, tr!("calendar-reminders"));
+            let givers: Vec<_> = wheel
+                .results()
+                .iter()
+                .map(|pair| pair.giver.clone())
+                .collect();
+            ui.add_enabled_ui(wheel.event.reminders, |ui| {
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut wheel.event.buy_by_days).clamp_range(0..=60));
+                    ui.label(tr!("calendar-days-before"));
+                });
+                let everyone = tr!("calendar-everyone");
+                egui::ComboBox::from_label(tr!("calendar-reminders-for"))
+                    .selected_text(
+                        wheel
+                            .reminders_for
+                            .as_ref()
+                            .map_or(everyone, |giver| &giver.name),
+                    )
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut wheel.reminders_for, None, everyone);
+                        for giver in givers {
+                            let name = giver.name.clone();
+                            ui.selectable_value(&mut wheel.reminders_for, Some(giver), name);
+                        }
+                    });
+                ui.weak(tr!("calendar-reminders-hint"));
+            });
+            if !wheel.hat.givers().is_empty() {
+                ui.weak(tr!(
+                    "calendar-partial",
+                    count = wheel.results().len(),
+                    remaining = wheel.hat.givers().len()
+                ));
+            }
+
+            ui.separator();
+            ui.horizontal(|ui| {
+                #[cfg(not(target_arch = "wasm32"))]
+                ui.add(
+                    egui::TextEdit::singleline(&mut wheel.calendar_path)
+                        .hint_text(DEFAULT_CALENDAR_PATH),
+                );
+                let save = ui
+                    .add_enabled(date.is_some(), egui::Button::new(tr!("calendar-save")))
+                    .on_disabled_hover_text(tr!("calendar-needs-date"));
+                if let (true, Some(date)) = (save.clicked(), date) {
+                    let text = calendar::ics(
+                        &wheel.event,
+                        date,
+                        &wheel.results(),
+                        wheel.reminders_for.as_ref(),
+                    );
+                    let path = match wheel.calendar_path.trim() {
+                        "" => DEFAULT_CALENDAR_PATH,
+                        path => path,
+                    };
+                    wheel.calendar_saved = Some(
+                        save::save(path, &text, "text/calendar")
+                            .map(|()| tr!("calendar-saved", path = path))
+                            .map_err(|err| tr!("save-failed", path = path, error = err)),
+                    );
+                }
+            });
+            saved_message(ui, &wheel.calendar_saved);
+        });
+    wheel.calendar_open = open;
+}
+
+/// Saving the results table and gift tags as a page to print from a browser.
+pub(super) fn print_window(ctx: &egui::Context, wheel: &mut WheelPage) {
+    let mut open = wheel.print_open;
+    egui::Window::new(tr!("print-title"))
+        .id(egui::Id::new("print-window"))
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(false)
+        .show(ctx, |ui| {
+            ui.label(tr!("print-help"));
+            ui.checkbox(&mut wheel.print_layout.results, tr!("print-results"));
+            ui.checkbox(&mut wheel.print_layout.tags, tr!("print-tags"));
+            ui.weak(tr!("print-event-hint"));
+            if !wheel.hat.givers().is_empty() {
+                ui.weak(tr!(
+                    "calendar-partial",
+                    count = wheel.results().len(),
+                    remaining = wheel.hat.givers().len()
+                ));
+            }
+
+            ui.separator();
+            let layout = wheel.print_layout;
+            ui.horizontal(|ui| {
+                #[cfg(not(target_arch = "wasm32"))]
+                ui.add(
+                    egui::TextEdit::singleline(&mut wheel.print_path).hint_text(DEFAULT_PRINT_PATH),
+                );
+                let can_save = (layout.results || layout.tags) && !wheel.results().is_empty();
+                if ui
+                    .add_enabled(can_save, egui::Button::new(tr!("print-save")))
+                    .clicked()
+                {
+                    let text = print::html(&wheel.event, &wheel.results(), layout);
+                    let path = match wheel.print_path.trim() {
+                        "" => DEFAULT_PRINT_PATH,
+                        path => path,
+                    };
+                    wheel.print_saved = Some(
+                        save::save(path, &text, "text/html")
+                            .map(|()| tr!("print-saved", path = path))
+                            .map_err(|err| tr!("save-failed", path = path, error = err)),
+                    );
+                }
+            });
+            saved_message(ui, &wheel.print_saved);
+        });
+    wheel.print_open = open;
+}
+
+fn saved_message(ui: &mut egui::Ui, saved: &Option<Result<String, String>>) {
+    match saved {
+        Some(Ok(msg)) => {
+            ui.label(msg);
+        }
+        Some(Err(msg)) => {
+            ui.colored_label(Color32::RED, msg);
+        }
+        None => (),
+    }
+}
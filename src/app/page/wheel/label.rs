@@ -0,0 +1,178 @@
+//! Fitting names onto the wedges of the wheel.
+//!
+//! Labels are laid out once for a wedge pointing along angle zero, then turned with the wheel
+//! each frame, so the fitting only has to be redone when the names or the wheel size change.
+
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+use eframe::epaint::{Fonts, TextShape};
+use egui::{emath::Rot2, Color32, FontId, Pos2, Shape, Vec2};
+
+/// Names are shrunk down to this size before they start being cut short.
+const MIN_FONT_SIZE: f32 = 9.0;
+/// Initials and numbers may go a little smaller, since there's nothing shorter to fall back to.
+const MIN_SHORT_FONT_SIZE: f32 = 6.0;
+/// A cut short name must keep at least this many characters, or it isn't worth showing.
+const MIN_CHARS: usize = 3;
+
+/// The slice of the wheel a label has to fit inside.
+pub(super) struct Wedge {
+    pub(super) radius: f32,
+    /// How far out from the middle straight labels may reach.
+    pub(super) outer: f32,
+    pub(super) inner_angle: f32,
+}
+
+impl Wedge {
+    fn max_font_size(&self) -> f32 {
+        self.radius * 0.1
+    }
+}
+
+/// Text fitted onto a wedge pointing along angle zero.
+#[derive(Clone, Debug)]
+pub(super) struct Label {
+    parts: Vec<Part>,
+    color: Color32,
+}
+
+#[derive(Clone, Debug)]
+struct Part {
+    text: String,
+    font_size: f32,
+    /// Where the top left of the text sits, relative to the middle of the wheel.
+    offset: Vec2,
+    angle: f32,
+}
+
+impl Label {
+    /// Adds the label to `shapes`, on a wedge whose middle points along `angle`.
+    pub(super) fn paint(&self, fonts: &Fonts, center: Pos2, angle: f32, shapes: &mut Vec<Shape>) {
+        let rotation = Rot2::from_angle(angle);
+        shapes.extend(self.parts.iter().map(|part| {
+            let galley = fonts.layout_no_wrap(
+                part.text.clone(),
+                FontId::proportional(part.font_size),
+                self.color,
+            );
+            let mut shape = TextShape::new(center + rotation * part.offset, galley);
+            shape.angle = angle + part.angle;
+            Shape::Text(shape)
+        }));
+    }
+}
+
+/// Writes `text` out from the middle of the wheel, ending just inside the rim.
+pub(super) fn straight(fonts: &Fonts, text: &str, wedge: &Wedge, color: Color32) -> Option<Label> {
+    straight_with_min(fonts, text, wedge, color, MIN_FONT_SIZE)
+}
+
+/// Writes `text` along the rim of the wheel.
+pub(super) fn curved(fonts: &Fonts, text: &str, wedge: &Wedge, color: Color32) -> Option<Label> {
+    let baseline = wedge.radius * 0.78;
+    // leave a little space either side so neighbouring names don't run together
+    let arc_length = wedge.inner_angle.min(TAU) * baseline * 0.9;
+    let (text, font_size) = fit(fonts, text, wedge.max_font_size(), MIN_FONT_SIZE, |_| {
+        arc_length
+    })?;
+
+    let font_id = FontId::proportional(font_size);
+    let galley = fonts.layout_no_wrap(text, font_id.clone(), color);
+    let row = galley.rows.first()?;
+    let start = -galley.size().x / 2.0 / baseline;
+
+    let parts = row
+        .glyphs
+        .iter()
+        .map(|glyph| {
+            let angle = start + (glyph.pos.x + glyph.size.x / 2.0) / baseline;
+            let dir = Vec2::angled(angle);
+            let along = Vec2::angled(angle + FRAC_PI_2);
+            let width = fonts.glyph_width(&font_id, glyph.chr);
+            let height = glyph.size.y;
+
+            // the tops of the letters face outwards, so they read clockwise around the rim
+            Part {
+                text: glyph.chr.to_string(),
+                font_size,
+                offset: dir * baseline - along * width / 2.0 + dir * height / 2.0,
+                angle: angle + FRAC_PI_2,
+            }
+        })
+        .collect();
+    Some(Label { parts, color })
+}
+
+/// Writes a short stand-in for a name on a wedge too thin for the name itself.
+pub(super) fn short(fonts: &Fonts, text: &str, wedge: &Wedge, color: Color32) -> Option<Label> {
+    straight_with_min(fonts, text, wedge, color, MIN_SHORT_FONT_SIZE)
+}
+
+fn straight_with_min(
+    fonts: &Fonts,
+    text: &str,
+    wedge: &Wedge,
+    color: Color32,
+    min_size: f32,
+) -> Option<Label> {
+    let outer = wedge.outer;
+    let inner = wedge.radius * 0.2;
+    let half_sin = (wedge.inner_angle.min(PI) / 2.0).sin();
+    // the wedge narrows towards the middle, so taller text has to stop further out
+    let room = |height: f32| outer - (height / (2.0 * half_sin)).max(inner);
+    let (text, font_size) = fit(fonts, text, wedge.max_font_size(), min_size, room)?;
+
+    let size = fonts
+        .layout_no_wrap(text.clone(), FontId::proportional(font_size), color)
+        .size();
+    let part = Part {
+        text,
+        font_size,
+        offset: Vec2::new(outer - size.x, -size.y / 2.0),
+        angle: 0.0,
+    };
+    Some(Label {
+        parts: vec![part],
+        color,
+    })
+}
+
+/// Finds the largest font size `text` fits at, shrinking it down to `min_size` before cutting it
+/// short with an ellipsis. `room` gives the width available to a line of the given height.
+fn fit(
+    fonts: &Fonts,
+    text: &str,
+    max_size: f32,
+    min_size: f32,
+    room: impl Fn(f32) -> f32,
+) -> Option<(String, f32)> {
+    let mut size = max_size;
+    loop {
+        let font_id = FontId::proportional(size);
+        let galley = fonts.layout_no_wrap(text.to_owned(), font_id.clone(), Color32::WHITE);
+        let max_width = room(galley.size().y);
+        if galley.size().x <= max_width {
+            return Some((text.to_owned(), size));
+        }
+        if size <= min_size {
+            return Some((truncate(fonts, text, &font_id, max_width)?, size));
+        }
+        size = (size * 0.9).max(min_size);
+    }
+}
+
+/// Cuts `text` short with an ellipsis so it fits in `max_width`.
+fn truncate(fonts: &Fonts, text: &str, font_id: &FontId, max_width: f32) -> Option<String> {
+    let mut width = fonts.glyph_width(font_id, '…');
+    let mut end = 0;
+    for (idx, c) in text.char_indices() {
+        width += fonts.glyph_width(font_id, c);
+        if width > max_width {
+            break;
+        }
+        end = idx + c.len_utf8();
+    }
+
+    let kept = text[..end].trim_end();
+    (kept.chars().count() >= MIN_CHARS).then(|| format!("{kept}…"))
+}
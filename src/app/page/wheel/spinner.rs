@@ -1,19 +1,52 @@
-use eframe::emath;
-use eframe::epaint::TextShape;
-use egui::{Color32, FontId, Frame, Pos2, Rect, Shape, Stroke, Vec2};
+use std::collections::hash_map::DefaultHasher;
+use std::f32::consts::TAU;
+use std::hash::{Hash, Hasher};
+
+use eframe::emath::{self, Rot2};
+use eframe::epaint::Fonts;
+use egui::{
+    accesskit, Color32, FontId, Frame, Pos2, Rect, Sense, Shape, Stroke, Vec2, WidgetInfo,
+    WidgetType,
+};
 use serde::{Deserialize, Serialize};
 
+use crate::app::settings::{Easing, Settings, ShortLabels, SpinSettings};
+use crate::avatar::Avatar;
 use crate::hat::Person;
 
+use super::label;
+
 #[derive(Serialize, Deserialize, Debug)]
 pub(crate) enum SpinnerTarget {
+    /// Accelerate towards a speed at [`ACCELERATION`].
     Speed(f32),
+    /// Change speed following an easing curve over a fixed time.
+    Ease {
+        from: f32,
+        to: f32,
+        duration: f32,
+        elapsed: f32,
+        easing: Easing,
+    },
     Item(usize),
 }
 
+impl SpinnerTarget {
+    /// Gently turn between spins, or stay still if idle drift is turned off.
+    pub(crate) fn idle(settings: &SpinSettings) -> Self {
+        SpinnerTarget::Speed(if settings.idle_drift { IDLE_SPEED } else { 0.0 })
+    }
+}
+
 pub(crate) const ACCELERATION: f32 = 2.0;
 pub(crate) const IDLE_SPEED: f32 = 0.4;
-pub(crate) const FULL_SPEED: f32 = 5.0;
+/// Idle drift stops after this many seconds without any input, so a wheel left on screen comes
+/// to rest and stops redrawing.
+pub(crate) const IDLE_DRIFT_TIMEOUT: f32 = 60.0;
+/// Roughly how many pixels long each straight piece of a wedge's rim is.
+const ARC_SEGMENT_LENGTH: f32 = 4.0;
+/// Pictures are left off wedges too thin to show them at least this many pixels across.
+const MIN_AVATAR_SIZE: f32 = 12.0;
 
 #[derive(Serialize, Deserialize)]
 #[serde(default)]
@@ -22,6 +55,17 @@ pub(crate) struct Spinner {
     pub(crate) target: SpinnerTarget,
     angle: f32,
     speed: f32,
+    /// Set when a wedge boundary passes the pointer, until collected with [`Self::take_tick`].
+    #[serde(skip)]
+    ticked: bool,
+    /// How much of the wheel each item gets compared to the others. Empty for equal wedges.
+    #[serde(skip)]
+    pub(crate) weights: Vec<f32>,
+    #[serde(skip)]
+    geometry: Option<Geometry>,
+    /// When there was last any input, for [`IDLE_DRIFT_TIMEOUT`].
+    #[serde(skip)]
+    last_input: f32,
 }
 
 impl Default for Spinner {
@@ -31,112 +75,415 @@ impl Default for Spinner {
             target: SpinnerTarget::Speed(IDLE_SPEED),
             angle: 0.0,
             speed: IDLE_SPEED,
+            ticked: false,
+            weights: Vec::new(),
+            geometry: None,
+            last_input: 0.0,
         }
     }
 }
 
 impl Spinner {
-    pub(crate) fn speed(&self) -> f32 {
-        self.speed
+    /// Eases from the current speed to `to` over `duration` seconds.
+    pub(crate) fn ease_to(&mut self, to: f32, duration: f32, easing: Easing) {
+        self.target = SpinnerTarget::Ease {
+            from: self.speed,
+            to,
+            duration,
+            elapsed: 0.0,
+            easing,
+        };
     }
 
-    pub(crate) fn step_animation(&mut self, delta_time: f32) {
+    /// Brings the spinner to rest immediately.
+    pub(crate) fn stop_now(&mut self) {
+        self.speed = 0.0;
+        self.target = SpinnerTarget::Speed(0.0);
+    }
+
+    /// Whether the spinner has finished changing speed.
+    pub(crate) fn target_reached(&self) -> bool {
         match self.target {
+            SpinnerTarget::Speed(target_speed) => (target_speed - self.speed).abs() <= 0.05,
+            SpinnerTarget::Ease {
+                duration, elapsed, ..
+            } => elapsed >= duration,
+            SpinnerTarget::Item(_) => false,
+        }
+    }
+
+    pub(crate) fn step_animation(&mut self, delta_time: f32) {
+        match &mut self.target {
             SpinnerTarget::Speed(target_speed) => {
-                let speed_delta = target_speed - self.speed;
+                let speed_delta = *target_speed - self.speed;
                 self.speed +=
                     speed_delta.clamp(-delta_time * ACCELERATION, delta_time * ACCELERATION);
-                self.angle = (self.angle + self.speed * delta_time) % std::f32::consts::TAU;
+            }
+            SpinnerTarget::Ease {
+                from,
+                to,
+                duration,
+                elapsed,
+                easing,
+            } => {
+                *elapsed += delta_time;
+                let t = if *duration > 0.0 {
+                    *elapsed / *duration
+                } else {
+                    1.0
+                };
+                self.speed = *from + (*to - *from) * easing.apply(t);
             }
             SpinnerTarget::Item(_) => todo!(),
         }
+        let angle = self.angle + self.speed * delta_time;
+        // the pointer sits at angle zero, so a boundary has passed it whenever a different
+        // wedge is under it
+        let wedges = wedge_angles(self.items.len(), &self.weights);
+        let under_pointer = |angle: f32| {
+            let pointer = (-angle).rem_euclid(TAU);
+            wedges
+                .iter()
+                .position(|&(start, inner)| pointer >= start && pointer < start + inner)
+        };
+        if under_pointer(angle) != under_pointer(self.angle) {
+            self.ticked = true;
+        }
+        self.angle = angle % TAU;
     }
 
-    pub(crate) fn render(&self, ui: &mut egui::Ui) {
-        let text_color = if ui.visuals().dark_mode {
-            Color32::from_additive_luminance(196)
+    /// The target while no one is spinning: drifting if that's turned on, but only while the
+    /// window is focused and has had input recently.
+    pub(crate) fn idle_target(&mut self, ui: &egui::Ui, settings: &SpinSettings) -> SpinnerTarget {
+        let input = ui.input();
+        let time = input.time as f32;
+        if !input.events.is_empty() || input.pointer.is_moving() {
+            self.last_input = time;
+        }
+        if input.raw.has_focus && time - self.last_input < IDLE_DRIFT_TIMEOUT {
+            SpinnerTarget::idle(settings)
         } else {
-            Color32::from_black_alpha(240)
-        };
+            SpinnerTarget::Speed(0.0)
+        }
+    }
 
-        let colors = [Color32::YELLOW, Color32::GREEN, Color32::RED];
-        let stroke = Stroke::new(1.0, text_color);
+    /// Whether a wedge boundary has passed the pointer since the last call.
+    pub(crate) fn take_tick(&mut self) -> bool {
+        std::mem::take(&mut self.ticked)
+    }
 
-        Frame::canvas(ui.style()).show(ui, |ui| {
-            ui.ctx().request_repaint();
-            //let time = ui.input().time;
+    /// Whether the wheel has come to rest and will stay there until given a new target.
+    pub(crate) fn at_rest(&self) -> bool {
+        self.speed == 0.0 && self.target_reached()
+    }
 
-            let smaller_dimension = ui.available_width().min(ui.available_height());
+    /// Tells screen readers what the painted wheel is, since they can't see the names on it.
+    fn describe(&self, response: &egui::Response) {
+        let names: Vec<_> = self.items.iter().map(|p| p.name.as_str()).collect();
+        let description = match names.len() {
+            0 => tr!("wheel-description-empty").to_owned(),
+            1 => tr!("wheel-description-one", names = names[0]),
+            count => tr!("wheel-description", count = count, names = names.join(", ")),
+        };
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Other, &description));
+        if let Some(mut node) = response.ctx.accesskit_node(response.id) {
+            node.role = accesskit::Role::Image;
+        }
+    }
 
-            let desired_size = smaller_dimension * Vec2::new(1.0, 1.0);
-            let (_id, rect) = ui.allocate_space(desired_size);
+    /// Draws the wheel as large as fits.
+    pub(crate) fn render(&mut self, ui: &mut egui::Ui, settings: &Settings) -> RenderedWheel {
+        let stroke_color = if ui.visuals().dark_mode {
+            Color32::from_additive_luminance(196)
+        } else {
+            Color32::from_black_alpha(240)
+        };
 
-            let to_screen =
-                emath::RectTransform::from_to(Rect::from_x_y_ranges(-1.0..=1.0, -1.0..=1.0), rect);
+        let stroke = Stroke::new(1.0, stroke_color);
 
-            let center = to_screen * Pos2::new(0., 0.);
+        Frame::canvas(ui.style())
+            .show(ui, |ui| {
+                let smaller_dimension = ui.available_width().min(ui.available_height());
 
-            let mut shapes = vec![];
+                let desired_size = smaller_dimension * Vec2::new(1.0, 1.0);
+                let (rect, response) = ui.allocate_exact_size(desired_size, Sense::hover());
+                self.describe(&response);
 
-            let inner_angle = std::f32::consts::TAU / self.items.len().max(1) as f32;
+                let to_screen = emath::RectTransform::from_to(
+                    Rect::from_x_y_ranges(-1.0..=1.0, -1.0..=1.0),
+                    rect,
+                );
 
-            for (idx, person) in self.items.iter().enumerate() {
-                let start_angle = self.angle + inner_angle * idx as f32;
+                let center = to_screen * Pos2::new(0., 0.);
                 let r = smaller_dimension / 2.0 - 5.0;
-                shapes.push(wedge(
-                    center,
-                    r,
-                    start_angle,
-                    inner_angle,
-                    colors[idx % colors.len()],
-                    stroke,
-                ));
 
-                let font_id = FontId {
-                    size: r * 0.1,
-                    ..Default::default()
+                let colors = settings.wheel_colors();
+                let key = GeometryKey {
+                    contents: contents_hash(&self.items, &self.weights, colors),
+                    radius: r,
+                    curved_labels: settings.curved_labels,
+                    short_labels: settings.short_labels,
+                    pixels_per_point: ui.ctx().pixels_per_point(),
+                };
+                let geometry = match &mut self.geometry {
+                    Some(geometry) if geometry.key == key => geometry,
+                    cached => cached.insert(Geometry::new(
+                        key,
+                        &self.items,
+                        &self.weights,
+                        colors,
+                        &ui.fonts(),
+                    )),
                 };
-                let galley =
-                    ui.fonts()
-                        .layout_no_wrap(person.name.to_string(), font_id, text_color);
 
-                let dir = Vec2::angled(start_angle + inner_angle / 2.0);
-                let xoffset = r - galley.rect.width() - r * 0.1;
-                let yoffset = galley.rect.height() / 2.0;
+                let mut shapes = vec![];
 
-                let text_pos = center + dir * xoffset + dir.rot90() * yoffset;
+                let textures: Vec<_> = self
+                    .items
+                    .iter()
+                    .map(|p| p.avatar.as_ref().and_then(|a| a.texture(ui.ctx())))
+                    .collect();
 
-                let mut text_shape = TextShape::new(text_pos, galley);
-                text_shape.angle = start_angle + inner_angle / 2.0;
+                let fonts = ui.fonts();
+                for (idx, wedge) in geometry.wedges.iter().enumerate() {
+                    if wedge.inner_angle <= 0.0 {
+                        continue;
+                    }
+                    let start_angle = self.angle + wedge.start_angle;
+                    let mid_angle = start_angle + wedge.inner_angle / 2.0;
+                    let fill = colors[idx % colors.len()];
+                    shapes.push(wedge.shape(center, start_angle, fill, stroke));
 
-                shapes.push(Shape::Text(text_shape));
-            }
+                    if let Some(label) = &wedge.label {
+                        label.paint(&fonts, center, mid_angle, &mut shapes);
+                    }
+
+                    let (Some(slot), Some(avatar)) = (&wedge.avatar_slot, &self.items[idx].avatar)
+                    else {
+                        continue;
+                    };
+                    // pictures stay upright so faces are easy to recognize while spinning
+                    let pos = center + Vec2::angled(mid_angle) * slot.distance;
+                    match (avatar, &textures[idx]) {
+                        (Avatar::Emoji(emoji), _) => {
+                            let galley = fonts.layout_no_wrap(
+                                emoji.clone(),
+                                FontId::proportional(slot.size * 0.8),
+                                text_color(fill),
+                            );
+                            let top_left = pos - galley.size() / 2.0;
+                            shapes.push(Shape::galley(top_left, galley));
+                        }
+                        (Avatar::Photo { .. }, Some(texture)) => {
+                            let rect = Rect::from_center_size(pos, Vec2::splat(slot.size));
+                            let uv = Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0));
+                            shapes.push(Shape::image(texture.id(), rect, uv, Color32::WHITE));
+                            shapes.push(Shape::rect_stroke(rect, 0.0, stroke));
+                        }
+                        (Avatar::Photo { .. }, None) => (),
+                    }
+                }
+                drop(fonts);
 
-            ui.painter().extend(shapes);
-        });
+                ui.painter().extend(shapes);
+
+                RenderedWheel {
+                    rect,
+                    legend: geometry.legend.clone(),
+                }
+            })
+            .inner
     }
 }
 
-fn wedge(
-    center: Pos2,
-    r: f32,
+/// What the wedge outlines and labels were last worked out for.
+#[derive(PartialEq)]
+struct GeometryKey {
+    /// From [`contents_hash`], so checking the key each frame doesn't copy every name.
+    contents: u64,
+    radius: f32,
+    curved_labels: bool,
+    short_labels: ShortLabels,
+    pixels_per_point: f32,
+}
+
+/// Wedge outlines and fitted labels, kept between frames so they're only worked out again when
+/// the names on the wheel, its size, or the label settings change.
+struct Geometry {
+    key: GeometryKey,
+    wedges: Vec<CachedWedge>,
+    legend: Vec<(String, String)>,
+}
+
+struct CachedWedge {
+    /// Where the wedge starts, measured from the wheel's current angle.
     start_angle: f32,
+    /// Zero for people hidden from the wheel.
     inner_angle: f32,
-    fill: Color32,
-    stroke: Stroke,
-) -> Shape {
-    let arc_points = 30;
-    let mut points = Vec::with_capacity(arc_points + 1);
+    /// The rim of the wedge as if it started at angle zero, relative to the middle of the wheel.
+    arc: Vec<Vec2>,
+    /// The label, which may not fit at all on a tiny wheel.
+    label: Option<label::Label>,
+    /// Where the picture goes, if there is one and there's room for it.
+    avatar_slot: Option<AvatarSlot>,
+}
+
+struct AvatarSlot {
+    /// How far the middle of the picture is from the middle of the wheel.
+    distance: f32,
+    size: f32,
+}
 
-    if inner_angle < std::f32::consts::TAU - 0.01 {
-        points.push(center);
+impl AvatarSlot {
+    /// Fits a square picture into a wedge, centered `distance` out from the middle.
+    fn fit(radius: f32, inner_angle: f32, distance: f32) -> Option<Self> {
+        let width = 2.0 * distance * (inner_angle.min(std::f32::consts::PI) / 2.0).sin();
+        let size = (width * 0.7).min(radius * 0.2);
+        (size >= MIN_AVATAR_SIZE).then_some(Self { distance, size })
     }
+}
 
-    let step = inner_angle / arc_points as f32;
+/// Hashes everything about the wheel's items that changes its geometry: the names, whether each
+/// has a picture, which takes room from their label, the wedge weights and the colors.
+fn contents_hash(items: &[Person], weights: &[f32], colors: &[Color32]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for person in items {
+        person.name.hash(&mut hasher);
+        person.avatar.is_some().hash(&mut hasher);
+    }
+    for weight in weights {
+        weight.to_bits().hash(&mut hasher);
+    }
+    colors.hash(&mut hasher);
+    hasher.finish()
+}
+
+impl Geometry {
+    fn new(
+        key: GeometryKey,
+        items: &[Person],
+        weights: &[f32],
+        colors: &[Color32],
+        fonts: &Fonts,
+    ) -> Self {
+        let r = key.radius;
+        let mut legend = vec![];
+        let wedges = wedge_angles(items.len(), weights)
+            .into_iter()
+            .enumerate()
+            .map(|(idx, (start_angle, inner_angle))| {
+                // enough points that each straight piece of the rim is only a few pixels long
+                let segments = (inner_angle * r / ARC_SEGMENT_LENGTH)
+                    .ceil()
+                    .clamp(1.0, 128.0) as usize;
+                let step = inner_angle / segments as f32;
+                let arc = (0..=segments)
+                    .map(|a| r * Vec2::angled(a as f32 * step))
+                    .collect();
+
+                let avatar_slot = if items[idx].avatar.is_none() {
+                    None
+                } else if key.curved_labels {
+                    // curved names run around the rim, leaving the middle of the wedge free
+                    AvatarSlot::fit(r, inner_angle, r * 0.5)
+                } else {
+                    // straight names run up to the picture, which sits at the rim
+                    AvatarSlot::fit(r, inner_angle, r * 0.8).and_then(|slot| {
+                        AvatarSlot::fit(r, inner_angle, r * 0.92 - slot.size / 2.0)
+                    })
+                };
 
-    for a in (0..=arc_points).map(|a| start_angle + a as f32 * step) {
-        points.push(center + r * Vec2::new(a.cos(), a.sin()));
+                let outer = match (&avatar_slot, key.curved_labels) {
+                    (Some(slot), false) => slot.distance - slot.size / 2.0 - r * 0.03,
+                    _ => r * 0.92,
+                };
+                let area = label::Wedge {
+                    radius: r,
+                    outer,
+                    inner_angle,
+                };
+                let name = &items[idx].name;
+                let color = text_color(colors[idx % colors.len()]);
+                let label = if inner_angle <= 0.0 {
+                    None
+                } else {
+                    let name_label = if key.curved_labels {
+                        label::curved(fonts, name, &area, color)
+                    } else {
+                        label::straight(fonts, name, &area, color)
+                    };
+                    name_label.or_else(|| {
+                        let short = key.short_labels.for_name(name, idx);
+                        let short_label = label::short(fonts, &short, &area, color);
+                        legend.push((short, name.clone()));
+                        short_label
+                    })
+                };
+
+                CachedWedge {
+                    start_angle,
+                    inner_angle,
+                    arc,
+                    label,
+                    avatar_slot,
+                }
+            })
+            .collect();
+
+        Self {
+            key,
+            wedges,
+            legend,
+        }
+    }
+}
+
+impl CachedWedge {
+    fn shape(&self, center: Pos2, start_angle: f32, fill: Color32, stroke: Stroke) -> Shape {
+        let rotation = Rot2::from_angle(start_angle);
+        let mut points = Vec::with_capacity(self.arc.len() + 1);
+
+        if self.inner_angle < TAU - 0.01 {
+            points.push(center);
+        }
+        points.extend(self.arc.iter().map(|&offset| center + rotation * offset));
+
+        Shape::convex_polygon(points, fill, stroke)
     }
+}
 
-    Shape::convex_polygon(points, fill, stroke)
+/// The start and size of each wedge, measured from the wheel's current angle. Weights that don't
+/// match up with the items, or that are all zero, give every wedge the same size.
+fn wedge_angles(items: usize, weights: &[f32]) -> Vec<(f32, f32)> {
+    let total: f32 = weights.iter().sum();
+    let equal = weights.len() != items || total <= 0.0;
+    let mut start = 0.0;
+    (0..items)
+        .map(|idx| {
+            let inner_angle = if equal {
+                TAU / items as f32
+            } else {
+                TAU * weights[idx] / total
+            };
+            let wedge = (start, inner_angle);
+            start += inner_angle;
+            wedge
+        })
+        .collect()
+}
+
+/// Where the wheel was drawn, and what the short labels used on thin wedges stand for.
+pub(crate) struct RenderedWheel {
+    pub(crate) rect: Rect,
+    /// Pairs of short label and full name.
+    pub(crate) legend: Vec<(String, String)>,
+}
+
+/// Picks black or white text, whichever is easier to read on top of `fill`.
+fn text_color(fill: Color32) -> Color32 {
+    if egui::Rgba::from(fill).intensity() > 0.25 {
+        Color32::from_black_alpha(240)
+    } else {
+        Color32::from_white_alpha(240)
+    }
 }
@@ -0,0 +1,277 @@
+use egui::Color32;
+use serde::{Deserialize, Serialize};
+
+use super::shortcuts::Shortcuts;
+use crate::i18n::Language;
+
+#[derive(Deserialize, Serialize, Default)]
+#[serde(default)] // if we add new fields, give them default values when deserializing old state
+pub(crate) struct Settings {
+    pub(crate) language: Language,
+    pub(crate) theme: Theme,
+    pub(crate) palette: Palette,
+    /// Colors used by [`Palette::Custom`], kept around when switching to a preset.
+    pub(crate) custom_colors: Vec<Color32>,
+    pub(crate) spin: SpinSettings,
+    pub(crate) muted: bool,
+    /// Skips the confetti and name card shown when the wheel stops.
+    pub(crate) reduce_motion: bool,
+    /// Writes names along the rim of the wheel instead of out from the middle.
+    pub(crate) curved_labels: bool,
+    /// What to write on wedges too thin for a name.
+    pub(crate) short_labels: ShortLabels,
+    pub(crate) wedge_sizes: WedgeSizes,
+    /// Shrinks away the people a spin can't land on before the wheel starts turning.
+    pub(crate) narrow_before_spin: bool,
+    pub(crate) shortcuts: Shortcuts,
+}
+
+impl Settings {
+    pub(crate) fn wheel_colors(&self) -> &[Color32] {
+        match self.palette {
+            Palette::Custom if !self.custom_colors.is_empty() => &self.custom_colors,
+            Palette::Custom => Palette::Classic.colors(),
+            preset => preset.colors(),
+        }
+    }
+}
+
+/// Timing of a single spin, from the first push to the wheel coming to rest.
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+#[serde(default)]
+pub(crate) struct SpinSettings {
+    /// Seconds taken to reach top speed.
+    pub(crate) windup_time: f32,
+    /// Seconds spent at top speed.
+    pub(crate) spin_time: f32,
+    /// Seconds taken to slow from top speed to a stop.
+    pub(crate) stop_time: f32,
+    /// Top speed in radians per second.
+    pub(crate) top_speed: f32,
+    /// How the speed changes while winding up and slowing down.
+    pub(crate) easing: Easing,
+    /// Whether the wheel slowly turns between spins.
+    pub(crate) idle_drift: bool,
+}
+
+impl Default for SpinSettings {
+    fn default() -> Self {
+        Self {
+            windup_time: 2.5,
+            spin_time: 5.0,
+            stop_time: 2.5,
+            top_speed: 5.0,
+            easing: Easing::Linear,
+            idle_drift: true,
+        }
+    }
+}
+
+impl SpinSettings {
+    pub(crate) fn total_time(&self) -> f32 {
+        self.windup_time + self.spin_time + self.stop_time
+    }
+}
+
+#[derive(Deserialize, Serialize, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Easing {
+    /// Constant acceleration.
+    #[default]
+    Linear,
+    /// Changes speed quickly at first, then creeps towards the target.
+    EaseOutCubic,
+    /// Starts and finishes gently, changing speed fastest in the middle.
+    EaseInOutCubic,
+}
+
+impl Easing {
+    pub(crate) const ALL: [Easing; 3] =
+        [Easing::Linear, Easing::EaseOutCubic, Easing::EaseInOutCubic];
+
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            Easing::Linear => tr!("easing-linear"),
+            Easing::EaseOutCubic => tr!("easing-ease-out-cubic"),
+            Easing::EaseInOutCubic => tr!("easing-ease-in-out-cubic"),
+        }
+    }
+
+    /// Maps progress `t` in `0..=1` to how far the speed has moved towards its target.
+    pub(crate) fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseOutCubic => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOutCubic => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum WedgeSizes {
+    #[default]
+    Equal,
+    /// Sized by how many of the people still to spin could land on each wedge.
+    ByChances,
+    /// Only show the people the current spin could land on.
+    OnlyPossible,
+}
+
+impl WedgeSizes {
+    pub(crate) const ALL: [WedgeSizes; 3] = [
+        WedgeSizes::Equal,
+        WedgeSizes::ByChances,
+        WedgeSizes::OnlyPossible,
+    ];
+
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            WedgeSizes::Equal => tr!("wedges-equal"),
+            WedgeSizes::ByChances => tr!("wedges-by-chances"),
+            WedgeSizes::OnlyPossible => tr!("wedges-only-possible"),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum ShortLabels {
+    #[default]
+    Initials,
+    /// Numbers counting clockwise around the wheel.
+    Numbers,
+}
+
+impl ShortLabels {
+    pub(crate) const ALL: [ShortLabels; 2] = [ShortLabels::Initials, ShortLabels::Numbers];
+
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            ShortLabels::Initials => tr!("short-initials"),
+            ShortLabels::Numbers => tr!("short-numbers"),
+        }
+    }
+
+    /// The stand-in for `name`, which is on the `idx`th wedge.
+    pub(crate) fn for_name(self, name: &str, idx: usize) -> String {
+        match self {
+            ShortLabels::Initials => {
+                let initials: String = name
+                    .split_whitespace()
+                    .filter_map(|word| word.chars().next())
+                    .flat_map(char::to_uppercase)
+                    .take(3)
+                    .collect();
+                if initials.is_empty() {
+                    "?".to_owned()
+                } else {
+                    initials
+                }
+            }
+            ShortLabels::Numbers => (idx + 1).to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Theme {
+    #[default]
+    Light,
+    Dark,
+    /// Follow the operating system, falling back to light when it can't be detected.
+    System,
+}
+
+impl Theme {
+    pub(crate) const ALL: [Theme; 3] = [Theme::Light, Theme::Dark, Theme::System];
+
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            Theme::Light => tr!("theme-light"),
+            Theme::Dark => tr!("theme-dark"),
+            Theme::System => tr!("theme-system"),
+        }
+    }
+
+    pub(crate) fn visuals(self, system_theme: Option<eframe::Theme>) -> egui::Visuals {
+        match self {
+            Theme::Light => egui::Visuals::light(),
+            Theme::Dark => egui::Visuals::dark(),
+            Theme::System => match system_theme {
+                Some(eframe::Theme::Dark) => egui::Visuals::dark(),
+                Some(eframe::Theme::Light) | None => egui::Visuals::light(),
+            },
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Palette {
+    #[default]
+    Classic,
+    Festive,
+    Winter,
+    /// The Okabe-Ito palette, which stays distinguishable for the common kinds of color blindness.
+    ColorBlindSafe,
+    Custom,
+}
+
+impl Palette {
+    pub(crate) const ALL: [Palette; 5] = [
+        Palette::Classic,
+        Palette::Festive,
+        Palette::Winter,
+        Palette::ColorBlindSafe,
+        Palette::Custom,
+    ];
+
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            Palette::Classic => tr!("palette-classic"),
+            Palette::Festive => tr!("palette-festive"),
+            Palette::Winter => tr!("palette-winter"),
+            Palette::ColorBlindSafe => tr!("palette-color-blind-safe"),
+            Palette::Custom => tr!("palette-custom"),
+        }
+    }
+
+    pub(crate) fn colors(self) -> &'static [Color32] {
+        match self {
+            Palette::Classic | Palette::Custom => &CLASSIC,
+            Palette::Festive => &FESTIVE,
+            Palette::Winter => &WINTER,
+            Palette::ColorBlindSafe => &OKABE_ITO,
+        }
+    }
+}
+
+const CLASSIC: [Color32; 3] = [Color32::YELLOW, Color32::GREEN, Color32::RED];
+
+const FESTIVE: [Color32; 4] = [
+    Color32::from_rgb(192, 57, 43),
+    Color32::from_rgb(30, 132, 73),
+    Color32::from_rgb(241, 196, 15),
+    Color32::from_rgb(253, 254, 254),
+];
+
+const WINTER: [Color32; 4] = [
+    Color32::from_rgb(27, 79, 114),
+    Color32::from_rgb(93, 173, 226),
+    Color32::from_rgb(214, 234, 248),
+    Color32::from_rgb(46, 134, 193),
+];
+
+const OKABE_ITO: [Color32; 7] = [
+    Color32::from_rgb(230, 159, 0),
+    Color32::from_rgb(86, 180, 233),
+    Color32::from_rgb(0, 158, 115),
+    Color32::from_rgb(240, 228, 66),
+    Color32::from_rgb(0, 114, 178),
+    Color32::from_rgb(213, 94, 0),
+    Color32::from_rgb(204, 121, 167),
+];
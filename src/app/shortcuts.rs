@@ -0,0 +1,168 @@
+use egui::{Key, KeyboardShortcut, Modifiers};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Action {
+    ShowPeople,
+    ShowWheel,
+    ShowSettings,
+    ShowAbout,
+    AddPerson,
+    Spin,
+    NextSpin,
+    Undo,
+    Restart,
+    Present,
+    CheatSheet,
+}
+
+impl Action {
+    pub(crate) const ALL: [Action; 11] = [
+        Action::ShowPeople,
+        Action::ShowWheel,
+        Action::ShowSettings,
+        Action::ShowAbout,
+        Action::AddPerson,
+        Action::Spin,
+        Action::NextSpin,
+        Action::Undo,
+        Action::Restart,
+        Action::Present,
+        Action::CheatSheet,
+    ];
+
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            Action::ShowPeople => tr!("action-show-people"),
+            Action::ShowWheel => tr!("action-show-wheel"),
+            Action::ShowSettings => tr!("action-show-settings"),
+            Action::ShowAbout => tr!("action-show-about"),
+            Action::AddPerson => tr!("action-add-person"),
+            Action::Spin => tr!("action-spin"),
+            Action::NextSpin => tr!("action-next-spin"),
+            Action::Undo => tr!("action-undo"),
+            Action::Restart => tr!("action-restart"),
+            Action::Present => tr!("action-present"),
+            Action::CheatSheet => tr!("action-cheat-sheet"),
+        }
+    }
+
+    /// Plain keys, since shortcuts are ignored while typing anyway. On the web the browser
+    /// still gets keys the app uses, so F-keys and Ctrl/Cmd combinations would also switch tabs,
+    /// reload the page or open its help.
+    fn default_shortcut(self) -> Shortcut {
+        let (modifiers, key) = match self {
+            Action::ShowPeople => (Modifiers::NONE, Key::Num1),
+            Action::ShowWheel => (Modifiers::NONE, Key::Num2),
+            Action::ShowSettings => (Modifiers::NONE, Key::Num3),
+            Action::ShowAbout => (Modifiers::NONE, Key::Num4),
+            Action::AddPerson => (Modifiers::NONE, Key::A),
+            Action::Spin => (Modifiers::NONE, Key::Space),
+            Action::NextSpin => (Modifiers::NONE, Key::Enter),
+            Action::Undo => (Modifiers::NONE, Key::U),
+            Action::Restart => (Modifiers::NONE, Key::R),
+            Action::Present => (Modifiers::NONE, Key::P),
+            Action::CheatSheet => (Modifiers::NONE, Key::H),
+        };
+        Shortcut { modifiers, key }
+    }
+}
+
+/// A key together with the modifiers that have to be held with it.
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) struct Shortcut {
+    pub(crate) modifiers: Modifiers,
+    pub(crate) key: Key,
+}
+
+impl Shortcut {
+    pub(crate) fn format(self, ctx: &egui::Context) -> String {
+        ctx.format_shortcut(&KeyboardShortcut::new(self.modifiers, self.key))
+    }
+
+    fn activates_widgets(self) -> bool {
+        self.modifiers == Modifiers::NONE && matches!(self.key, Key::Space | Key::Enter)
+    }
+}
+
+/// Which shortcut runs each action. Actions missing from the list use their default, and ones
+/// bound to `None` have no shortcut.
+#[derive(Deserialize, Serialize, Clone, Default, PartialEq, Eq, Debug)]
+#[serde(default)]
+pub(crate) struct Shortcuts {
+    bindings: Vec<(Action, Option<Shortcut>)>,
+}
+
+impl Shortcuts {
+    pub(crate) fn get(&self, action: Action) -> Option<Shortcut> {
+        self.bindings
+            .iter()
+            .find(|(a, _)| *a == action)
+            .map_or_else(
+                || Some(action.default_shortcut()),
+                |(_, shortcut)| *shortcut,
+            )
+    }
+
+    /// Binds `shortcut` to `action`, taking it away from any other action that had it.
+    pub(crate) fn set(&mut self, action: Action, shortcut: Option<Shortcut>) {
+        for other in Action::ALL {
+            if other != action && shortcut.is_some() && self.get(other) == shortcut {
+                self.bind(other, None);
+            }
+        }
+        self.bind(action, shortcut);
+    }
+
+    fn bind(&mut self, action: Action, shortcut: Option<Shortcut>) {
+        self.bindings.retain(|(a, _)| *a != action);
+        self.bindings.push((action, shortcut));
+    }
+
+    /// A description of the shortcut for `action`, for showing next to it.
+    pub(crate) fn describe(&self, action: Action, ctx: &egui::Context) -> String {
+        match self.get(action) {
+            Some(shortcut) => shortcut.format(ctx),
+            None => tr!("shortcut-none").to_owned(),
+        }
+    }
+
+    /// The actions whose shortcuts were pressed this frame, taking the key presses so nothing
+    /// else reacts to them too.
+    pub(crate) fn pressed(&self, ctx: &egui::Context) -> Vec<Action> {
+        // a focused button is pressed with space or enter, so leave those keys alone for it
+        let widget_focused = ctx.memory().focus().is_some();
+        let mut input = ctx.input_mut();
+        Action::ALL
+            .into_iter()
+            .filter(|&action| match self.get(action) {
+                Some(shortcut) if widget_focused && shortcut.activates_widgets() => false,
+                Some(shortcut) => input.consume_key(shortcut.modifiers, shortcut.key),
+                None => false,
+            })
+            .collect()
+    }
+}
+
+/// Lists every action with its shortcut.
+pub(crate) fn cheat_sheet(ctx: &egui::Context, open: &mut bool, shortcuts: &Shortcuts) {
+    egui::Window::new(tr!("shortcuts-title"))
+        .id(egui::Id::new("cheat-sheet"))
+        .open(open)
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .show(ctx, |ui| {
+            egui::Grid::new("cheat-sheet")
+                .num_columns(2)
+                .striped(true)
+                .show(ui, |ui| {
+                    for action in Action::ALL {
+                        ui.label(action.label());
+                        ui.monospace(shortcuts.describe(action, ctx));
+                        ui.end_row();
+                    }
+                });
+            ui.weak(tr!("shortcuts-hint"));
+        });
+}
@@ -0,0 +1,137 @@
+//! Pictures shown next to people's names, for those who recognize a face sooner than a name.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, BTreeSet};
+use std::hash::{Hash, Hasher};
+use std::io::Cursor;
+
+use egui::{ColorImage, TextureHandle, TextureOptions};
+use image::imageops::FilterType;
+use serde::{Deserialize, Serialize};
+
+/// Photos are cropped and shrunk to this many pixels square when loaded.
+const PHOTO_SIZE: u32 = 96;
+
+/// Emoji to pick from instead of a photo.
+pub(crate) const BUILT_IN: [&str; 24] = [
+    "🎅", "🤶", "🦌", "⛄", "🎄", "⭐", "🐶", "🐱", "🐭", "🐰", "🦊", "🐻", "🐼", "🐨", "🐯", "🦁",
+    "🐸", "🐵", "🐧", "🐢", "🐙", "🦄", "🚀", "🌈",
+];
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum Avatar {
+    Emoji(String),
+    /// A photo kept in [`Photos`]. People are copied into the hat, the wheel and every pair
+    /// they're in, so they only carry the id.
+    Photo {
+        /// A hash of the photo's PNG, which is cheaper to compare and to look it up by.
+        id: u64,
+        /// Only filled in for state saved before photos were kept in [`Photos`], until
+        /// [`Photos::adopt`] moves it there.
+        #[serde(rename = "png", default, skip_serializing)]
+        legacy_png: Vec<u8>,
+    },
+}
+
+impl PartialEq for Avatar {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Avatar::Emoji(a), Avatar::Emoji(b)) => a == b,
+            (Avatar::Photo { id: a, .. }, Avatar::Photo { id: b, .. }) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Avatar {}
+
+impl Avatar {
+    /// The photo as a texture, once [`Photos::load_textures`] has uploaded it. `None` for emoji.
+    pub(crate) fn texture(&self, ctx: &egui::Context) -> Option<TextureHandle> {
+        let Avatar::Photo { id, .. } = self else {
+            return None;
+        };
+        ctx.data()
+            .get_temp::<Option<TextureHandle>>(texture_key(*id))?
+    }
+}
+
+fn texture_key(id: u64) -> egui::Id {
+    egui::Id::new(("avatar", id))
+}
+
+/// Every photo in use, stored once each as base64 PNG text, since saved state is text and photos
+/// written out as lists of numbers would soon fill the browser's storage.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct Photos {
+    png: BTreeMap<u64, String>,
+}
+
+impl Photos {
+    /// Reads a photo from the contents of an image file, cropping it to a square, and keeps it.
+    pub(crate) fn add(&mut self, bytes: &[u8]) -> Result<Avatar, String> {
+        let image =
+            image::load_from_memory(bytes).map_err(|err| tr!("avatar-read-failed", error = err))?;
+        let image = image.resize_to_fill(PHOTO_SIZE, PHOTO_SIZE, FilterType::Triangle);
+
+        let mut png = Vec::new();
+        image
+            .write_to(&mut Cursor::new(&mut png), image::ImageOutputFormat::Png)
+            .map_err(|err| tr!("avatar-store-failed", error = err))?;
+
+        let mut hasher = DefaultHasher::new();
+        png.hash(&mut hasher);
+        let id = hasher.finish();
+        self.png.entry(id).or_insert_with(|| base64::encode(&png));
+        Ok(Avatar::Photo {
+            id,
+            legacy_png: Vec::new(),
+        })
+    }
+
+    /// Moves a photo from state saved before there was a store into it.
+    pub(crate) fn adopt(&mut self, avatar: &mut Avatar) {
+        if let Avatar::Photo { id, legacy_png } = avatar {
+            if !legacy_png.is_empty() {
+                let png = std::mem::take(legacy_png);
+                self.png.entry(*id).or_insert_with(|| base64::encode(png));
+            }
+        }
+    }
+
+    /// Forgets photos no one has any more.
+    pub(crate) fn retain_used<'a>(&mut self, avatars: impl Iterator<Item = &'a Avatar>) {
+        let used: BTreeSet<u64> = avatars
+            .filter_map(|avatar| match avatar {
+                Avatar::Photo { id, .. } => Some(*id),
+                Avatar::Emoji(_) => None,
+            })
+            .collect();
+        self.png.retain(|id, _| used.contains(id));
+    }
+
+    /// Uploads any photos that don't have a texture yet.
+    pub(crate) fn load_textures(&self, ctx: &egui::Context) {
+        for (&id, encoded) in &self.png {
+            let key = texture_key(id);
+            if ctx.data().get_temp::<Option<TextureHandle>>(key).is_some() {
+                continue;
+            }
+            // remember photos that fail to decode too, so they aren't retried every frame
+            let texture = base64::decode(encoded)
+                .ok()
+                .and_then(|png| image::load_from_memory(&png).ok())
+                .map(|image| {
+                    let image = image.to_rgba8();
+                    let size = [image.width() as usize, image.height() as usize];
+                    ctx.load_texture(
+                        format!("avatar-{id}"),
+                        ColorImage::from_rgba_unmultiplied(size, image.as_raw()),
+                        TextureOptions::LINEAR,
+                    )
+                });
+            ctx.data().insert_temp(key, texture);
+        }
+    }
+}
@@ -0,0 +1,383 @@
+//! Writing the exchange and the results as an iCalendar (`.ics`) file, so people can import the
+//! date and a reminder to buy their gift into whatever calendar they use.
+//!
+//! Follows RFC 5545: lines end in CRLF, are folded at 75 octets, and text values escape
+//! backslashes, commas, semicolons and newlines.
+
+use std::fmt::Write;
+
+use crate::hat::{Pair, Person};
+
+/// Details about the exchange itself, filled in by the organizer.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug)]
+#[serde(default)]
+pub(crate) struct Event {
+    pub(crate) name: String,
+    /// As typed, `YYYY-MM-DD`. Checked with [`Date::parse`] when exporting.
+    pub(crate) date: String,
+    pub(crate) location: String,
+    /// Anything else worth knowing, like the spending limit.
+    pub(crate) notes: String,
+    /// Whether to add a reminder for each giver to buy their gift.
+    pub(crate) reminders: bool,
+    /// How many days before the exchange the reminders are.
+    pub(crate) buy_by_days: u32,
+}
+
+impl Default for Event {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            date: String::new(),
+            location: String::new(),
+            notes: String::new(),
+            reminders: true,
+            buy_by_days: 7,
+        }
+    }
+}
+
+/// A day on the calendar, with no time zone.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Date {
+    year: i64,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// Reads a `YYYY-MM-DD` date, returning `None` if it isn't one or the day doesn't exist.
+    pub(crate) fn parse(text: &str) -> Option<Date> {
+        let mut parts = text.trim().splitn(3, '-');
+        let year = parts.next()?.parse().ok()?;
+        let month = parts.next()?.parse().ok()?;
+        let day = parts.next()?.parse().ok()?;
+        let date = Date { year, month, day };
+        // round-tripping through a day count catches 31 November and 29 February on other years
+        (Date::from_days(date.days()) == date && (1..=9999).contains(&year)).then_some(date)
+    }
+
+    pub(crate) fn add_days(self, days: i64) -> Date {
+        Date::from_days(self.days() + days)
+    }
+
+    /// Days since 1970-01-01, from Howard Hinnant's `days_from_civil`.
+    fn days(self) -> i64 {
+        let year = if self.month <= 2 {
+            self.year - 1
+        } else {
+            self.year
+        };
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = i64::from(self.month);
+        let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// The inverse of [`Date::days`].
+    fn from_days(days: i64) -> Date {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        } as u32;
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        Date { year, month, day }
+    }
+
+    /// As iCalendar writes a `DATE` value, `YYYYMMDD`.
+    fn compact(self) -> String {
+        format!("{:04}{:02}{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl std::fmt::Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Builds the calendar: an all-day event for the exchange and, if the event asks for them, a
+/// reminder for each giver in `pairs` to buy their gift, or only for `reminders_for` if given.
+pub(crate) fn ics(
+    event: &Event,
+    date: Date,
+    pairs: &[&Pair],
+    reminders_for: Option<&Person>,
+) -> String {
+    let stamp = timestamp(now());
+    let name = if event.name.trim().is_empty() {
+        tr!("calendar-default-name")
+    } else {
+        event.name.trim()
+    };
+    // the date and event name keep ids the same when the same exchange is exported again, so
+    // calendars update the events instead of adding copies
+    let uid_base = format!("{}-{}", date.compact(), slug(name));
+
+    let mut out = Lines::default();
+    out.push("BEGIN", "VCALENDAR");
+    out.push("VERSION", "2.0");
+    out.push("PRODID", "-//Gift Exchange Wheel//EN");
+    out.push("CALSCALE", "GREGORIAN");
+    out.push("METHOD", "PUBLISH");
+
+    out.push("BEGIN", "VEVENT");
+    out.push("UID", &format!("{uid_base}@gift-exchange-wheel"));
+    out.push("DTSTAMP", &stamp);
+    out.push("DTSTART;VALUE=DATE", &date.compact());
+    out.push("DTEND;VALUE=DATE", &date.add_days(1).compact());
+    out.push("SUMMARY", &escape(name));
+    if !event.location.trim().is_empty() {
+        out.push("LOCATION", &escape(event.location.trim()));
+    }
+    if !event.notes.trim().is_empty() {
+        out.push("DESCRIPTION", &escape(event.notes.trim()));
+    }
+    out.push("TRANSP", "TRANSPARENT");
+    out.push("END", "VEVENT");
+
+    if event.reminders {
+        let buy_by = date.add_days(-i64::from(event.buy_by_days));
+        let reminders = pairs
+            .iter()
+            .enumerate()
+            .filter(|(_, pair)| reminders_for.map_or(true, |giver| *giver == pair.giver));
+        for (idx, pair) in reminders {
+            let summary = tr!("calendar-buy-gift", receiver = pair.receiver.name);
+            let description = tr!(
+                "calendar-buy-gift-description",
+                giver = pair.giver.name,
+                receiver = pair.receiver.name,
+                event = name,
+                date = date
+            );
+            out.push("BEGIN", "VEVENT");
+            // names alone can clash, for people with the same name in different groups or names
+            // with no letters or digits, so the pair's place in the results keeps ids apart,
+            // including when only one giver's reminder is exported
+            out.push(
+                "UID",
+                &format!(
+                    "{uid_base}-{idx}-{}@gift-exchange-wheel",
+                    slug(&pair.giver.name)
+                ),
+            );
+            out.push("DTSTAMP", &stamp);
+            out.push("DTSTART;VALUE=DATE", &buy_by.compact());
+            out.push("DTEND;VALUE=DATE", &buy_by.add_days(1).compact());
+            out.push("SUMMARY", &escape(&summary));
+            out.push("DESCRIPTION", &escape(&description));
+            out.push("TRANSP", "TRANSPARENT");
+            // nine in the morning on the day, wherever the calendar is
+            out.push("BEGIN", "VALARM");
+            out.push("ACTION", "DISPLAY");
+            out.push("DESCRIPTION", &escape(&summary));
+            out.push("TRIGGER", "PT9H");
+            out.push("END", "VALARM");
+            out.push("END", "VEVENT");
+        }
+    }
+
+    out.push("END", "VCALENDAR");
+    out.0
+}
+
+/// Content lines, each folded and ended with CRLF as they're added.
+#[derive(Default)]
+struct Lines(String);
+
+impl Lines {
+    fn push(&mut self, name: &str, value: &str) {
+        let line = format!("{name}:{value}");
+        // fold at 75 octets without splitting a character, continuing on a line starting with a
+        // space, which counts towards the next 75
+        let mut width = 0;
+        for c in line.chars() {
+            if width + c.len_utf8() > 75 {
+                self.0.push_str("\r\n ");
+                width = 1;
+            }
+            self.0.push(c);
+            width += c.len_utf8();
+        }
+        self.0.push_str("\r\n");
+    }
+}
+
+/// Escapes a `TEXT` value.
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\n' => out.push_str("\\n"),
+            '\r' => (),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Letters and digits from `text`, for building ids.
+fn slug(text: &str) -> String {
+    let mut out = String::new();
+    for c in text.chars() {
+        if c.is_alphanumeric() {
+            out.extend(c.to_lowercase());
+        } else if !out.is_empty() && !out.ends_with('-') {
+            out.push('-');
+        }
+    }
+    out.trim_end_matches('-').to_owned()
+}
+
+/// A UTC `DATE-TIME`, `YYYYMMDDTHHMMSSZ`.
+fn timestamp(seconds: i64) -> String {
+    let date = Date::from_days(seconds.div_euclid(86_400));
+    let seconds = seconds.rem_euclid(86_400);
+    let mut out = date.compact();
+    let _ = write!(
+        out,
+        "T{:02}{:02}{:02}Z",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+    out
+}
+
+/// Seconds since 1970-01-01 UTC.
+#[cfg(not(target_arch = "wasm32"))]
+fn now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64)
+}
+
+/// Seconds since 1970-01-01 UTC. The standard library has no clock on the web.
+#[cfg(target_arch = "wasm32")]
+fn now() -> i64 {
+    (js_sys::Date::now() / 1000.0) as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i64, month: u32, day: u32) -> Date {
+        Date { year, month, day }
+    }
+
+    #[test]
+    fn parses_real_dates_only() {
+        assert_eq!(Date::parse(" 2026-12-24 "), Some(date(2026, 12, 24)));
+        assert_eq!(Date::parse("2026-1-5"), Some(date(2026, 1, 5)));
+        assert_eq!(Date::parse("2024-02-29"), Some(date(2024, 2, 29)));
+        assert_eq!(Date::parse("2000-02-29"), Some(date(2000, 2, 29)));
+        for bad in [
+            "2023-02-29",
+            "1900-02-29",
+            "2026-11-31",
+            "2026-13-01",
+            "2026-00-10",
+            "2026-01-00",
+            "0000-01-01",
+            "2026-12",
+            "2026-12-24-1",
+            "24/12/2026",
+            "",
+        ] {
+            assert_eq!(Date::parse(bad), None, "{bad}");
+        }
+    }
+
+    #[test]
+    fn counts_days() {
+        assert_eq!(date(1970, 1, 1).days(), 0);
+        assert_eq!(date(2000, 3, 1).days(), 11_017);
+        assert_eq!(date(1969, 12, 31).days(), -1);
+        for days in -800_000..800_000 {
+            assert_eq!(Date::from_days(days).days(), days);
+        }
+        assert_eq!(date(2024, 2, 28).add_days(1), date(2024, 2, 29));
+        assert_eq!(date(2024, 2, 28).add_days(2), date(2024, 3, 1));
+        assert_eq!(date(2023, 12, 31).add_days(1), date(2024, 1, 1));
+        assert_eq!(date(2026, 12, 24).add_days(-30), date(2026, 11, 24));
+        assert_eq!(date(2026, 1, 5).to_string(), "2026-01-05");
+        assert_eq!(date(2026, 1, 5).compact(), "20260105");
+    }
+
+    #[test]
+    fn folds_long_lines() {
+        let value = "é".repeat(100);
+        let mut lines = Lines::default();
+        lines.push("SUMMARY", &value);
+        let text = lines.0.strip_suffix("\r\n").unwrap();
+        for line in text.split("\r\n") {
+            assert!(line.len() <= 75, "{} octets: {line}", line.len());
+        }
+        assert_eq!(text.replace("\r\n ", ""), format!("SUMMARY:{value}"));
+
+        let mut lines = Lines::default();
+        lines.push("VERSION", "2.0");
+        assert_eq!(lines.0, "VERSION:2.0\r\n");
+    }
+
+    #[test]
+    fn escapes_text() {
+        assert_eq!(
+            escape("Smith, Jr; \\o/\r\nBring snacks"),
+            "Smith\\, Jr\\; \\\\o/\\nBring snacks"
+        );
+    }
+
+    #[test]
+    fn timestamps() {
+        assert_eq!(timestamp(0), "19700101T000000Z");
+        assert_eq!(timestamp(1_700_000_000), "20231114T221320Z");
+    }
+
+    fn uids(text: &str) -> Vec<&str> {
+        text.lines()
+            .filter_map(|line| line.strip_prefix("UID:"))
+            .collect()
+    }
+
+    #[test]
+    fn reminder_ids_are_unique() {
+        let pairs = [
+            Pair::fixed(Person::new("Alex", "Smith"), Person::new("Sam", "Lee")),
+            Pair::fixed(Person::new("Alex", "Jones"), Person::new("Alex", "Smith")),
+            Pair::fixed(Person::new("🎅", "🎄"), Person::new("Alex", "Jones")),
+            Pair::fixed(Person::new("🤶", "🎄"), Person::new("🎅", "🎄")),
+        ];
+        let pairs: Vec<_> = pairs.iter().collect();
+        let event = Event::default();
+        let date = Date::parse("2026-12-24").unwrap();
+
+        let everyone = ics(&event, date, &pairs, None);
+        let mut ids = uids(&everyone);
+        assert_eq!(ids.len(), 5);
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), 5);
+
+        // exporting one giver's reminder again updates the same event
+        let one = ics(&event, date, &pairs, Some(&pairs[2].giver));
+        assert_eq!(uids(&one)[1], uids(&everyone)[3]);
+    }
+}
@@ -0,0 +1,51 @@
+//! Fonts for names in scripts egui's built-in fonts don't cover.
+//!
+//! The built-in fonts stop at Latin, Greek and Cyrillic, so names in Chinese, Japanese or Korean
+//! would show up as boxes. Fonts covering them are too large to bundle, so the native app borrows
+//! one already installed on the computer. The web app has no way to do the same, so it still shows
+//! boxes for those names.
+
+/// Installed fonts covering CJK scripts, in order of preference.
+#[cfg(not(target_arch = "wasm32"))]
+const CANDIDATES: &[&str] = &[
+    // Linux
+    "/usr/share/fonts/opentype/noto/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/noto-cjk/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/google-noto-cjk/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/truetype/wqy/wqy-microhei.ttc",
+    "/usr/share/fonts/truetype/droid/DroidSansFallbackFull.ttf",
+    // macOS
+    "/System/Library/Fonts/Supplemental/Arial Unicode.ttf",
+    "/Library/Fonts/Arial Unicode.ttf",
+    "/System/Library/Fonts/Hiragino Sans GB.ttc",
+    // Windows
+    "C:\\Windows\\Fonts\\msyh.ttc",
+    "C:\\Windows\\Fonts\\YuGothM.ttc",
+    "C:\\Windows\\Fonts\\malgun.ttf",
+];
+
+/// Adds the first installed CJK font as a fallback after the built-in fonts.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn install_fallback_fonts(ctx: &egui::Context) {
+    let Some(bytes) = CANDIDATES.iter().find_map(|path| std::fs::read(path).ok()) else {
+        return;
+    };
+
+    let mut fonts = egui::FontDefinitions::default();
+    fonts
+        .font_data
+        .insert("cjk".to_owned(), egui::FontData::from_owned(bytes));
+    for family in [egui::FontFamily::Proportional, egui::FontFamily::Monospace] {
+        fonts
+            .families
+            .entry(family)
+            .or_default()
+            .push("cjk".to_owned());
+    }
+    ctx.set_fonts(fonts);
+}
+
+/// The browser can't hand over its fonts, so the web app makes do with the built-in ones and CJK
+/// names show up as boxes.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn install_fallback_fonts(_ctx: &egui::Context) {}
@@ -1,10 +1,17 @@
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+use crate::avatar::Avatar;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Person {
     pub name: String,
     pub group: String,
+    #[serde(default)]
+    pub avatar: Option<Avatar>,
+    /// Where to send them their result, if known.
+    #[serde(default)]
+    pub email: String,
 }
 
 impl Person {
@@ -12,14 +19,60 @@ impl Person {
         Self {
             name: name.into(),
             group: group.into(),
+            avatar: None,
+            email: String::new(),
         }
     }
 }
 
+// The avatar and email are left out so that changing someone's picture or address doesn't make
+// them a different person to the hat, the wheel, or any fixed pairs they're in.
+impl PartialEq for Person {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.group == other.group
+    }
+}
+
+impl Eq for Person {}
+
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
 pub struct Pair {
     pub giver: Person,
     pub receiver: Person,
+    /// Set for pairs the organizer decided on ahead of time rather than drawn from the hat.
+    #[serde(default)]
+    pub fixed: bool,
+}
+
+impl Pair {
+    pub fn fixed(giver: Person, receiver: Person) -> Self {
+        Self {
+            giver,
+            receiver,
+            fixed: true,
+        }
+    }
+
+    pub fn same_people(&self, other: &Pair) -> bool {
+        self.giver == other.giver && self.receiver == other.receiver
+    }
+}
+
+/// Which side of a pair someone is on.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    #[default]
+    Giver,
+    Receiver,
+}
+
+impl Role {
+    pub fn other(self) -> Role {
+        match self {
+            Role::Giver => Role::Receiver,
+            Role::Receiver => Role::Giver,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Default)]
@@ -30,8 +83,8 @@ pub struct Hat {
 
 #[derive(Debug)]
 pub enum DrawError {
-    NoGivers,
-    NoValidReceiver,
+    NotInHat,
+    NoValidMatch,
 }
 
 impl Hat {
@@ -42,42 +95,151 @@ impl Hat {
         }
     }
 
-    pub fn draw_name<F>(&mut self, validate_pair: F) -> Result<Pair, DrawError>
+    /// Draws a receiver for a specific giver who is still in the hat.
+    pub fn draw_name_for<F>(&mut self, giver: &Person, validate_pair: F) -> Result<Pair, DrawError>
     where
         F: Fn(&Person, &Person) -> bool,
     {
+        self.draw(Role::Giver, giver, validate_pair)
+    }
+
+    /// Draws a giver for a specific receiver who is still in the hat.
+    pub fn draw_giver_for<F>(
+        &mut self,
+        receiver: &Person,
+        validate_pair: F,
+    ) -> Result<Pair, DrawError>
+    where
+        F: Fn(&Person, &Person) -> bool,
+    {
+        self.draw(Role::Receiver, receiver, validate_pair)
+    }
+
+    /// Draws someone from the other pool for `person`, who takes the part of `role`.
+    fn draw<F>(&mut self, role: Role, person: &Person, validate_pair: F) -> Result<Pair, DrawError>
+    where
+        F: Fn(&Person, &Person) -> bool,
+    {
+        let Some(own_idx) = self.pool(role).iter().position(|p| p == person) else {
+            return Err(DrawError::NotInHat);
+        };
         let mut rng = rand::thread_rng();
-        let Some(giver) = self.givers.pop() else { return Err(DrawError::NoGivers); };
+        let person = self.pool_mut(role).remove(own_idx);
+        let other = role.other();
+        let len = self.pool(other).len();
 
-        let start_idx = rng.gen_range(0..self.receivers.len());
+        let start_idx = rng.gen_range(0..len);
 
-        for offset in 0..self.receivers.len() {
-            let idx = (start_idx + offset) % self.receivers.len();
-            let receiver = &self.receivers[idx];
+        for offset in 0..len {
+            let idx = (start_idx + offset) % len;
+            let candidate = &self.pool(other)[idx];
 
-            if !validate_pair(&giver, receiver) {
+            let valid = match role {
+                Role::Giver => validate_pair(&person, candidate),
+                Role::Receiver => validate_pair(candidate, &person),
+            };
+            if !valid {
                 continue;
             }
 
-            let receiver = self.receivers.remove(idx);
+            let candidate = self.pool_mut(other).remove(idx);
 
             if self.valid_solution_exists(&validate_pair) {
-                let pair = Pair { giver, receiver };
+                let (giver, receiver) = match role {
+                    Role::Giver => (person, candidate),
+                    Role::Receiver => (candidate, person),
+                };
+                let pair = Pair {
+                    giver,
+                    receiver,
+                    fixed: false,
+                };
                 return Ok(pair);
             }
 
             //fixup on failure
-            self.receivers.insert(idx, receiver);
+            self.pool_mut(other).insert(idx, candidate);
         }
 
-        Err(DrawError::NoValidReceiver)
+        self.pool_mut(role).insert(own_idx, person);
+        Err(DrawError::NoValidMatch)
+    }
+
+    /// Takes the giver and receiver of a predetermined pair out of the pools.
+    ///
+    /// Returns false, leaving the hat untouched, if either of them is no longer available.
+    pub fn fix_pair(&mut self, pair: &Pair) -> bool {
+        let giver = self.givers.iter().position(|p| p == &pair.giver);
+        let receiver = self.receivers.iter().position(|p| p == &pair.receiver);
+        let (Some(giver), Some(receiver)) = (giver, receiver) else {
+            return false;
+        };
+        self.givers.remove(giver);
+        self.receivers.remove(receiver);
+        true
+    }
+
+    /// Everyone still in the hat, whether waiting to give or to receive.
+    pub fn people_mut(&mut self) -> impl Iterator<Item = &mut Person> {
+        self.givers.iter_mut().chain(self.receivers.iter_mut())
+    }
+
+    /// Adds someone who joined after the draw started to both pools.
+    pub fn add_person(&mut self, person: Person) {
+        self.givers.push(person.clone());
+        self.receivers.push(person);
+    }
+
+    /// Puts both pools in the same order as `people`, with anyone not in it last.
+    pub fn follow_order(&mut self, people: &[Person]) {
+        let position = |person: &Person| {
+            people
+                .iter()
+                .position(|p| p == person)
+                .unwrap_or(people.len())
+        };
+        self.givers.sort_by_key(position);
+        self.receivers.sort_by_key(position);
+    }
+
+    /// Removes someone from whichever pools they are still in.
+    pub fn remove_person(&mut self, person: &Person) {
+        self.givers.retain(|p| p != person);
+        self.receivers.retain(|p| p != person);
+    }
+
+    /// Returns both halves of an already drawn pair to the hat.
+    pub fn put_back(&mut self, pair: Pair) {
+        self.givers.push(pair.giver);
+        self.receivers.push(pair.receiver);
+    }
+
+    /// Puts a drawn pair back so they're the first to be drawn again.
+    pub fn put_back_first(&mut self, pair: Pair) {
+        self.givers.insert(0, pair.giver);
+        self.receivers.insert(0, pair.receiver);
+    }
+
+    /// The people still waiting to be drawn in the given role.
+    pub(crate) fn pool(&self, role: Role) -> &[Person] {
+        match role {
+            Role::Giver => &self.givers,
+            Role::Receiver => &self.receivers,
+        }
+    }
+
+    fn pool_mut(&mut self, role: Role) -> &mut Vec<Person> {
+        match role {
+            Role::Giver => &mut self.givers,
+            Role::Receiver => &mut self.receivers,
+        }
     }
 
     pub(crate) fn givers(&self) -> &[Person] {
         &self.givers
     }
 
-    fn valid_solution_exists<F>(&self, validate_pair: &F) -> bool
+    pub fn valid_solution_exists<F>(&self, validate_pair: &F) -> bool
     where
         F: Fn(&Person, &Person) -> bool,
     {
@@ -95,7 +257,9 @@ impl Hat {
         F: Fn(&Person, &Person) -> bool,
     {
         //success if there are no more givers to assign
-        let Some(giver) = givers.pop() else { return true; };
+        let Some(giver) = givers.pop() else {
+            return true;
+        };
 
         let iter = receivers
             .iter()
@@ -119,3 +283,198 @@ impl Hat {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::valid_pair;
+
+    /// Everyone in their own group, so anyone can give to anyone but themselves.
+    fn people(names: &[&str]) -> Vec<Person> {
+        names.iter().map(|name| Person::new(name, name)).collect()
+    }
+
+    fn pools(hat: &Hat) -> (Vec<Person>, Vec<Person>) {
+        (
+            hat.pool(Role::Giver).to_vec(),
+            hat.pool(Role::Receiver).to_vec(),
+        )
+    }
+
+    #[test]
+    fn draws_for_the_chosen_giver() {
+        let people = people(&["Alice", "Bob", "Carol"]);
+        let mut hat = Hat::with_people(people.clone());
+        let pair = hat.draw_name_for(&people[1], valid_pair).unwrap();
+        assert_eq!(pair.giver, people[1]);
+        assert_ne!(pair.receiver, people[1]);
+        assert!(!pair.fixed);
+        let (givers, receivers) = pools(&hat);
+        assert_eq!(givers, [people[0].clone(), people[2].clone()]);
+        assert_eq!(receivers.len(), 2);
+        assert!(!receivers.contains(&pair.receiver));
+    }
+
+    #[test]
+    fn drawing_for_someone_not_in_the_hat_changes_nothing() {
+        let people = people(&["Alice", "Bob", "Carol"]);
+        let mut hat = Hat::with_people(people.clone());
+        let drawn = hat.draw_name_for(&people[0], valid_pair).unwrap();
+        let before = pools(&hat);
+        assert!(matches!(
+            hat.draw_name_for(&drawn.giver, valid_pair),
+            Err(DrawError::NotInHat)
+        ));
+        assert!(matches!(
+            hat.draw_name_for(&Person::new("Dave", "Dave"), valid_pair),
+            Err(DrawError::NotInHat)
+        ));
+        assert_eq!(pools(&hat), before);
+    }
+
+    #[test]
+    fn drawing_with_no_valid_match_changes_nothing() {
+        // all in one group, so no one can give to anyone
+        let people: Vec<_> = ["Alice", "Bob", "Carol"]
+            .into_iter()
+            .map(|name| Person::new(name, "Smith"))
+            .collect();
+        let mut hat = Hat::with_people(people.clone());
+        assert!(matches!(
+            hat.draw_name_for(&people[1], valid_pair),
+            Err(DrawError::NoValidMatch)
+        ));
+        assert_eq!(pools(&hat), (people.clone(), people));
+    }
+
+    #[test]
+    fn drawing_turns_down_matches_that_leave_no_solution() {
+        // Alice could give to Bob or Carol, but they can only both give to Alice, so every match
+        // for her is turned down
+        let people = vec![
+            Person::new("Alice", "Smith"),
+            Person::new("Bob", "Jones"),
+            Person::new("Carol", "Jones"),
+        ];
+        let mut hat = Hat::with_people(people.clone());
+        assert!(!hat.valid_solution_exists(&valid_pair));
+        assert!(matches!(
+            hat.draw_name_for(&people[0], valid_pair),
+            Err(DrawError::NoValidMatch)
+        ));
+        assert_eq!(pools(&hat), (people.clone(), people));
+    }
+
+    #[test]
+    fn draws_for_the_chosen_receiver() {
+        let people = people(&["Alice", "Bob", "Carol"]);
+        let mut hat = Hat::with_people(people.clone());
+        let pair = hat.draw_giver_for(&people[1], valid_pair).unwrap();
+        assert_eq!(pair.receiver, people[1]);
+        assert_ne!(pair.giver, people[1]);
+        let (givers, receivers) = pools(&hat);
+        assert_eq!(receivers, [people[0].clone(), people[2].clone()]);
+        assert_eq!(givers.len(), 2);
+        assert!(!givers.contains(&pair.giver));
+    }
+
+    #[test]
+    fn failed_draws_for_receivers_change_nothing() {
+        let people: Vec<_> = ["Alice", "Bob", "Carol"]
+            .into_iter()
+            .map(|name| Person::new(name, "Smith"))
+            .collect();
+        let mut hat = Hat::with_people(people.clone());
+        assert!(matches!(
+            hat.draw_giver_for(&people[2], valid_pair),
+            Err(DrawError::NoValidMatch)
+        ));
+        assert!(matches!(
+            hat.draw_giver_for(&Person::new("Dave", "Dave"), valid_pair),
+            Err(DrawError::NotInHat)
+        ));
+        assert_eq!(pools(&hat), (people.clone(), people));
+    }
+
+    #[test]
+    fn pools_stay_balanced_whichever_way_the_draw_goes() {
+        let people = people(&["Alice", "Bob", "Carol", "Dave", "Erin"]);
+        for _ in 0..50 {
+            let mut hat = Hat::with_people(people.clone());
+            let mut pairs = Vec::new();
+            // alternate, drawing for whoever is first in the pool being spun for
+            for role in [Role::Giver, Role::Receiver].into_iter().cycle() {
+                let Some(person) = hat.pool(role).first().cloned() else {
+                    break;
+                };
+                let pair = match role {
+                    Role::Giver => hat.draw_name_for(&person, valid_pair),
+                    Role::Receiver => hat.draw_giver_for(&person, valid_pair),
+                };
+                pairs.push(pair.unwrap());
+                assert_eq!(hat.pool(Role::Giver).len(), hat.pool(Role::Receiver).len());
+            }
+            assert_eq!(pools(&hat), (vec![], vec![]));
+            for person in &people {
+                assert_eq!(pairs.iter().filter(|p| p.giver == *person).count(), 1);
+                assert_eq!(pairs.iter().filter(|p| p.receiver == *person).count(), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn fixed_pair_leaves_both_pools() {
+        let people = people(&["Alice", "Bob", "Carol"]);
+        let mut hat = Hat::with_people(people.clone());
+        assert!(hat.fix_pair(&Pair::fixed(people[0].clone(), people[1].clone())));
+        assert_eq!(
+            pools(&hat),
+            (
+                vec![people[1].clone(), people[2].clone()],
+                vec![people[0].clone(), people[2].clone()]
+            )
+        );
+    }
+
+    #[test]
+    fn fixed_pair_is_never_drawn_again() {
+        let people = people(&["Alice", "Bob", "Carol", "Dave"]);
+        let fixed = Pair::fixed(people[0].clone(), people[1].clone());
+        // the draw is random, so try it a good few times
+        for _ in 0..50 {
+            let mut hat = Hat::with_people(people.clone());
+            assert!(hat.fix_pair(&fixed));
+            assert!(matches!(
+                hat.draw_name_for(&people[0], valid_pair),
+                Err(DrawError::NotInHat)
+            ));
+            for giver in &people[1..] {
+                let pair = hat.draw_name_for(giver, valid_pair).unwrap();
+                assert_ne!(pair.receiver, fixed.receiver);
+            }
+            assert_eq!(pools(&hat), (vec![], vec![]));
+        }
+    }
+
+    #[test]
+    fn fix_pair_refuses_someone_already_taken() {
+        let people = people(&["Alice", "Bob", "Carol"]);
+        let mut hat = Hat::with_people(people.clone());
+        assert!(hat.fix_pair(&Pair::fixed(people[0].clone(), people[1].clone())));
+        let before = pools(&hat);
+        assert!(!hat.fix_pair(&Pair::fixed(people[0].clone(), people[2].clone())));
+        assert!(!hat.fix_pair(&Pair::fixed(people[2].clone(), people[1].clone())));
+        assert_eq!(pools(&hat), before);
+    }
+
+    #[test]
+    fn fixed_pairs_can_leave_no_solution() {
+        let people = people(&["Alice", "Bob", "Carol"]);
+        let mut hat = Hat::with_people(people.clone());
+        assert!(hat.fix_pair(&Pair::fixed(people[0].clone(), people[1].clone())));
+        assert!(hat.valid_solution_exists(&valid_pair));
+        // Carol would be left to give to herself
+        assert!(hat.fix_pair(&Pair::fixed(people[1].clone(), people[0].clone())));
+        assert!(!hat.valid_solution_exists(&valid_pair));
+    }
+}
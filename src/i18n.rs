@@ -0,0 +1,209 @@
+//! Translations of the text shown in the app.
+//!
+//! Each language has a catalog in `i18n/<code>.ftl`, written in a small subset of
+//! [Fluent](https://projectfluent.org): `key = text` lines, `#` comments, and `{ $name }`
+//! placeables filled in by [`tr!`]. Text missing from a catalog falls back to English.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+/// Looks up the text for `key` in the current language, filling in any placeables given as
+/// `name = value` pairs.
+macro_rules! tr {
+    ($key:literal) => {
+        $crate::i18n::lookup($key)
+    };
+    ($key:literal, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::i18n::format(
+            $crate::i18n::lookup($key),
+            &[$((stringify!($name), &$value as &dyn std::fmt::Display)),+],
+        )
+    };
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub(crate) enum Language {
+    #[default]
+    English,
+    Spanish,
+    German,
+    French,
+}
+
+impl Language {
+    pub(crate) const ALL: [Language; 4] = [
+        Language::English,
+        Language::Spanish,
+        Language::German,
+        Language::French,
+    ];
+
+    /// The language's name in itself, so people can find their own in the list.
+    pub(crate) fn native_name(self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Spanish => "Español",
+            Language::German => "Deutsch",
+            Language::French => "Français",
+        }
+    }
+
+    /// The BCP 47 tag, as used for the language of exported documents.
+    pub(crate) fn code(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Spanish => "es",
+            Language::German => "de",
+            Language::French => "fr",
+        }
+    }
+
+    /// Whether the language is written right to left, so rows of widgets should be too.
+    pub(crate) fn right_to_left(self) -> bool {
+        match self {
+            Language::English | Language::Spanish | Language::German | Language::French => false,
+        }
+    }
+
+    fn catalog(self) -> &'static str {
+        match self {
+            Language::English => include_str!("i18n/en.ftl"),
+            Language::Spanish => include_str!("i18n/es.ftl"),
+            Language::German => include_str!("i18n/de.ftl"),
+            Language::French => include_str!("i18n/fr.ftl"),
+        }
+    }
+
+    fn from_index(index: u8) -> Self {
+        Language::ALL
+            .get(index as usize)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn index(self) -> u8 {
+        Language::ALL.iter().position(|&l| l == self).unwrap_or(0) as u8
+    }
+}
+
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+/// Catalogs parsed so far, loaded the first time a language is used.
+static CATALOGS: Mutex<Vec<(Language, Catalog)>> = Mutex::new(Vec::new());
+
+type Catalog = HashMap<&'static str, &'static str>;
+
+pub(crate) fn set_language(language: Language) {
+    CURRENT.store(language.index(), Ordering::Relaxed);
+}
+
+pub(crate) fn language() -> Language {
+    Language::from_index(CURRENT.load(Ordering::Relaxed))
+}
+
+/// The text for `key` in the current language. Use [`tr!`] rather than calling this directly.
+pub(crate) fn lookup(key: &'static str) -> &'static str {
+    let mut catalogs = CATALOGS.lock().unwrap_or_else(|err| err.into_inner());
+    [language(), Language::English]
+        .into_iter()
+        .find_map(|language| {
+            let idx = match catalogs.iter().position(|(l, _)| *l == language) {
+                Some(idx) => idx,
+                None => {
+                    catalogs.push((language, parse(language.catalog())));
+                    catalogs.len() - 1
+                }
+            };
+            catalogs[idx].1.get(key).copied()
+        })
+        .unwrap_or(key)
+}
+
+/// Fills in the `{ $name }` placeables in `text`. Use [`tr!`] rather than calling this directly.
+pub(crate) fn format(text: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+        let name = rest[start + 1..start + end].trim().trim_start_matches('$');
+        match args.iter().find(|(arg, _)| *arg == name) {
+            Some((_, value)) => out.push_str(&value.to_string()),
+            None => out.push_str(&rest[start..start + end + 1]),
+        }
+        rest = &rest[start + end + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn parse(source: &'static str) -> Catalog {
+    source
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, text)| (key.trim(), text.trim()))
+        .collect()
+}
+
+/// Lays widgets out in a row running the same way as the current language's writing.
+pub(crate) fn horizontal<R>(
+    ui: &mut egui::Ui,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> egui::InnerResponse<R> {
+    row(ui, language().right_to_left(), add_contents)
+}
+
+/// A row running right to left or left to right, for [`horizontal`].
+fn row<R>(
+    ui: &mut egui::Ui,
+    right_to_left: bool,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> egui::InnerResponse<R> {
+    let layout = if right_to_left {
+        egui::Layout::right_to_left(egui::Align::Center)
+    } else {
+        egui::Layout::left_to_right(egui::Align::Center)
+    };
+    let size = egui::vec2(
+        ui.available_size_before_wrap().x,
+        ui.spacing().interact_size.y,
+    );
+    ui.allocate_ui_with_layout(size, layout, add_contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Where the left edges of two labels added one after the other end up in a row.
+    fn label_positions(right_to_left: bool) -> (f32, f32) {
+        let ctx = egui::Context::default();
+        let mut positions = (0.0, 0.0);
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                positions = row(ui, right_to_left, |ui| {
+                    (
+                        ui.label("first").rect.left(),
+                        ui.label("second").rect.left(),
+                    )
+                })
+                .inner;
+            });
+        });
+        positions
+    }
+
+    #[test]
+    fn rows_follow_the_writing_direction() {
+        let (first, second) = label_positions(false);
+        assert!(first < second, "{first} {second}");
+        let (first, second) = label_positions(true);
+        assert!(first > second, "{first} {second}");
+    }
+}
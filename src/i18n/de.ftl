@@ -0,0 +1,253 @@
+# Deutsch
+
+## Navigation
+app-title = Wichtel-Glücksrad
+nav-people = Personen
+nav-wheel = Glücksrad
+nav-settings = Einstellungen
+nav-about = Über
+nav-shortcuts-hint = Tastenkürzel ({ $shortcut })
+about-powered-by = erstellt mit
+about-and = und
+
+## People page
+person-name = Name:
+person-group = Gruppe:
+people-picture = Bild:
+people-add = Hinzufügen
+people-drop-hint = Lass das Bild auf jemanden fallen, um es als sein Bild zu verwenden.
+open-failed = { $path } konnte nicht geöffnet werden: { $error }
+avatar-menu = Bild…
+avatar-none = Kein Bild
+avatar-path-hint = Pfad zu einem Foto
+avatar-load = Laden
+avatar-drop-hint = Oder zieh eine Bilddatei auf jemanden in der Liste.
+avatar-read-failed = Das Bild konnte nicht gelesen werden: { $error }
+avatar-store-failed = Das Bild konnte nicht gespeichert werden: { $error }
+fixed-heading = Feste Paare
+fixed-description = Paare, die schon vor dem Drehen feststehen.
+fixed-giver = Schenkende Person
+fixed-receiver = Beschenkte Person
+fixed-add = Paar hinzufügen
+fixed-pick-both = Wähle eine schenkende und eine beschenkte Person
+fixed-self = Niemand kann sich selbst beschenken
+fixed-already-giving = { $name } beschenkt schon jemanden
+fixed-already-receiving = { $name } wird schon von jemandem beschenkt
+fixed-impossible = Mit diesem Paar lassen sich nicht mehr alle anderen zuordnen
+
+## Wheel page
+order-in-order = Der Reihe nach
+order-random = Zufällige Reihenfolge
+order-chosen = Organisator wählt
+find-receivers = Für jede schenkende Person eine beschenkte
+find-givers = Für jede beschenkte Person eine schenkende
+wheel-spin-order = Reihenfolge
+wheel-spin-to-find = Drehen, um zu finden
+wheel-remaining-givers = Noch ohne Beschenkte
+wheel-remaining-receivers = Noch ohne Schenkende
+wheel-results = Ergebnisse
+wheel-fixed = (fest)
+wheel-already-spun = Für { $name } wurde schon gedreht
+wheel-impossible = Es lassen sich nicht alle zuordnen
+wheel-unfixable = Schon gezogen, daher konnten diese Paare nicht festgelegt werden: { $pairs }
+wheel-pick-next = Wähle unter { $list }, für wen als Nächstes gedreht wird
+wheel-no-one-left = Niemand mehr übrig
+wheel-all-drawn = Alle Namen gezogen
+wheel-spin = Rad drehen
+wheel-draw-all = Alle sofort ziehen
+wheel-draw-all-hint = Alle Übrigen ohne Drehen ziehen
+wheel-next-spin = Nächste Runde
+wheel-fast-forward = Vorspulen
+wheel-restart = Neu starten
+wheel-undo = Rückgängig
+wheel-undo-hint = Das letzte Ergebnis zurück in den Hut legen
+wheel-present = Präsentieren
+wheel-present-hint = Zeigt das Rad im Vollbild. { $spin } dreht, { $next } geht weiter, Esc beendet.
+wheel-legend = Legende
+wheel-description-empty = Wichtel-Glücksrad, leer
+wheel-description-one = Wichtel-Glücksrad mit 1 Namen: { $names }
+wheel-description = Wichtel-Glücksrad mit { $count } Namen: { $names }
+status-giving = { $giver } beschenkt { $receiver }
+status-buying = { $giver } kauft für { $receiver }
+status-spinning-for = Es wird gedreht für { $name }
+status-who-buys = Wer kauft für { $name }?
+present-up-next = Als Nächstes: { $name }
+present-whos-next = Wer ist als Nächstes dran?
+present-spin-hint = { $shortcut } drücken zum Drehen
+present-next-hint = { $shortcut } drücken für die nächste Runde
+present-exit = Beenden (Esc)
+sync-changed = Die Personenliste hat sich seit Beginn der Ziehung geändert: { $added } hinzugefügt, { $removed } entfernt
+sync-reordered = Die Reihenfolge der Liste hat sich seit Beginn der Ziehung geändert
+sync-fixed-pair = { $sign } festes Paar
+sync-update = Rad aktualisieren
+sync-update-hint = Behält die Ergebnisse, außer Paaren mit jemandem, der entfernt wurde
+sync-wait = Warte, bis die aktuelle Drehung fertig ist
+sync-or-restart = oder Neu starten, um die Ziehung neu zu beginnen
+
+## Settings page
+settings-preview = Vorschau
+preview-spin = Probedrehen
+preview-stop = Vorschau stoppen
+settings-language = Sprache
+settings-theme = Design
+theme-light = Hell
+theme-dark = Dunkel
+theme-system = Wie das System
+settings-wheel-colors = Radfarben
+palette-classic = Klassisch
+palette-festive = Festlich
+palette-winter = Winter
+palette-color-blind-safe = Für Farbenblinde
+palette-custom = Eigene
+colors-add = Farbe hinzufügen
+settings-wedge-sizes = Größe der Felder
+wedges-equal = Alle gleich groß
+wedges-by-chances = Größer für Personen, auf die mehr Drehungen fallen können
+wedges-only-possible = Personen ausblenden, auf die diese Drehung nicht fallen kann
+settings-narrow = Vor jeder Drehung die Personen schrumpfen lassen, auf die sie nicht fallen kann
+settings-wheel-labels = Beschriftung
+settings-curved = Namen am Rand entlang schreiben
+settings-short-labels = Wenn ein Name nicht passt, zeige:
+short-initials = Initialen
+short-numbers = Nummern
+settings-effects = Effekte
+settings-sound = Soundeffekte abspielen
+settings-sound-not-built = Dieser Build hat keinen Ton. Native Builds brauchen das Feature `sound`, siehe README.
+settings-sound-no-device = Ton konnte nicht abgespielt werden, da sich kein Audiogerät öffnen ließ.
+settings-confetti = Konfetti, wenn das Rad anhält
+settings-spin = Drehung
+spin-presets = Vorlagen:
+spin-quick = Schnell
+spin-standard = Standard
+spin-dramatic = Dramatisch
+spin-wind-up = Anlauf
+spin-full-speed = Volle Geschwindigkeit
+spin-slow-down = Abbremsen
+spin-top-speed = Höchstgeschwindigkeit
+spin-curve = Geschwindigkeitskurve
+easing-linear = Linear
+easing-ease-out-cubic = Kubisch auslaufend
+easing-ease-in-out-cubic = Kubisch an- und auslaufend
+spin-idle-drift = Zwischen den Drehungen langsam weiterdrehen
+spin-total-time = Jede Drehung dauert etwa { $seconds } s
+settings-shortcuts = Tastenkürzel
+settings-press-key = Taste drücken…
+settings-shortcut-hint = Klicken, dann das neue Kürzel drücken. Esc bricht ab.
+settings-clear = Entfernen
+settings-reset-shortcuts = Kürzel zurücksetzen
+
+## Keyboard shortcuts
+action-show-people = Zu Personen
+action-show-wheel = Zum Glücksrad
+action-show-settings = Zu Einstellungen
+action-show-about = Zu Über
+action-add-person = Person hinzufügen
+action-spin = Rad drehen
+action-next-spin = Nächste Runde
+action-undo = Letztes Ergebnis rückgängig machen
+action-restart = Ziehung neu starten
+action-present = Präsentationsmodus
+action-cheat-sheet = Diese Kürzel anzeigen
+shortcut-none = Keins
+shortcuts-title = Tastenkürzel
+shortcuts-hint = Tab springt zwischen Schaltflächen und Feldern. Kürzel lassen sich in den Einstellungen ändern.
+
+## Confirmations
+confirm-cancel = Abbrechen
+confirm-copy = Vorher in die Zwischenablage kopieren
+confirm-copy-hint = Eine Kopie von dem behalten, was verloren geht
+confirm-restart-title = Ziehung neu starten?
+confirm-restart-pairs = { $drawn } von { $total } gezogenen Paaren werden gelöscht
+confirm-remove-title = { $name } entfernen?
+confirm-remove = Entfernen
+confirm-remove-person = { $name } wird von der Liste genommen
+confirm-remove-fixed = Das feste Paar { $giver } ==> { $receiver } wird entfernt
+confirm-remove-picture = Das Bild geht verloren
+
+## Bulk entry
+bulk-open = Mehrere hinzufügen…
+bulk-title = Mehrere Personen hinzufügen
+bulk-help = Eine Person pro Zeile als "Name, Gruppe" oder eine ganze Gruppe als "Gruppe: Name, Name". Tabulatoren gehen auch.
+bulk-add = { $count } Personen hinzufügen
+bulk-errors-kept = Zeilen mit Fehlern bleiben zum Korrigieren im Feld.
+bulk-contacts-skipped = { $count } Kontakte ausgelassen:
+import-duplicate = { $name } ({ $group }) steht schon auf der Liste
+import-missing-group = Vor dem ":" fehlt die Gruppe
+import-no-names = Keine Namen nach "{ $group }:"
+import-no-group = Keine Gruppe für { $name }, schreib es als "{ $name }, Gruppe"
+import-too-many = Zu viele Kommas, schreib eine Person als "Name, Gruppe"
+
+## Ordering
+people-search = Suchen
+people-sort = Sortieren nach:
+people-sort-name = Name
+people-sort-group = Gruppe
+people-order-hint = Es wird in dieser Reihenfolge gedreht. Zieh ☰, um jemanden zu verschieben.
+people-drag-hint = Zum Verschieben ziehen
+
+## Contacts
+person-email = E-Mail:
+person-optional = optional
+bulk-contacts-help = Als .vcf-Datei exportierte Kontakte lassen sich einfügen, öffnen oder auf die Seite ziehen.
+bulk-contacts-path = Pfad zu einer .vcf-Datei
+bulk-open-file = Öffnen
+vcard-group-from = Gruppe aus
+vcard-family-name = Nachname
+vcard-organization = Organisation
+vcard-category = Erste Kategorie
+vcard-no-group = { $name } hat kein Feld „{ $field }“ für die Gruppe
+
+## Calendar
+calendar-open = Zum Kalender hinzufügen…
+calendar-title = Zum Kalender hinzufügen
+calendar-name = Wichteln:
+calendar-default-name = Wichteln
+calendar-date = Datum:
+calendar-location = Ort:
+calendar-notes = Notizen:
+calendar-notes-hint = Preisgrenze, Motto, …
+calendar-bad-date = Schreib das Datum als JJJJ-MM-TT, zum Beispiel 2024-12-24
+calendar-reminders = Alle Schenkenden erinnern, ihr Geschenk zu kaufen
+calendar-days-before = Tage vor dem Wichteln
+calendar-reminders-for = Erinnerungen für
+calendar-everyone = Alle
+calendar-reminders-hint = Wähle eine Person, um eine Datei nur für sie zu erstellen, ohne die Ergebnisse der anderen.
+calendar-partial = Nur die { $count } bisher gezogenen Paare sind enthalten, { $remaining } fehlen noch.
+calendar-save = Kalender speichern
+calendar-needs-date = Zuerst das Datum eintragen
+calendar-saved = { $path } gespeichert
+save-failed = { $path } konnte nicht gespeichert werden: { $error }
+calendar-buy-gift = Geschenk für { $receiver } kaufen
+calendar-buy-gift-description = { $giver } beschenkt { $receiver } bei { $event } am { $date }.
+
+## Printing
+print-open = Drucken…
+print-title = Ergebnisse drucken
+print-help = Speichert eine Seite, die im Browser geöffnet und gedruckt werden kann.
+print-results = Tabelle, wer wen beschenkt
+print-tags = Ein Geschenkanhänger zum Ausschneiden für jede beschenkte Person
+print-event-hint = Name, Datum und Ort des Wichtelns kommen aus „Zum Kalender hinzufügen“.
+print-save = Seite speichern
+print-saved = { $path } gespeichert, zum Drucken im Browser öffnen
+print-hint = Drucke diese Seite aus dem Browser. Die Anhänger beginnen auf einer neuen Seite, entlang der gestrichelten Linien ausschneiden.
+print-tag-to = Für: { $name }
+print-tag-from = Von: deinem Wichtel
+
+## Sharing
+share-button = Link teilen…
+share-title = Link teilen
+share-help = Wer diesen Link im Browser öffnet, bekommt diese Liste mit Personen und festen Paaren.
+share-address = Adresse der Web-App:
+share-needs-address = Trag ein, wo die Web-Version der App liegt, um einen Link zu erstellen.
+share-copy = Link kopieren
+share-length = { $length } Zeichen
+share-left-out = Fotos und E-Mail-Adressen sind nicht enthalten.
+share-too-long = Manche Apps kürzen so lange Links. Falls er sich nicht öffnet, versuch es auf anderem Weg.
+share-open-title = Geteilte Einstellungen öffnen?
+share-open = Öffnen
+share-open-replaces = Ersetzt die { $people } Personen und { $pairs } festen Paare, die jetzt hier sind
+share-bad-link = Dieser Link enthält keine Einstellungen, die die App lesen kann. Vielleicht wurde er beim Senden abgeschnitten.
+share-newer-version = Dieser Link stammt von einer neueren Version der App. Lade die Seite neu.
+share-bad-line = Zeile { $line } der geteilten Einstellungen kann nicht gelesen werden
+share-received = Einen Link bekommen? Hier einfügen, um ihn zu öffnen:
+share-paste-hint = Link
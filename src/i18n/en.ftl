@@ -0,0 +1,253 @@
+# English. Every key used in the app has to be here, since other languages fall back to it.
+
+## Navigation
+app-title = Gift Exchange Wheel
+nav-people = People
+nav-wheel = Wheel
+nav-settings = Settings
+nav-about = About
+nav-shortcuts-hint = Keyboard shortcuts ({ $shortcut })
+about-powered-by = powered by
+about-and = and
+
+## People page
+person-name = Name:
+person-group = Group:
+people-picture = Picture:
+people-add = Add
+people-drop-hint = Drop the image onto someone to use it as their picture.
+open-failed = Couldn't open { $path }: { $error }
+avatar-menu = Picture…
+avatar-none = No picture
+avatar-path-hint = Path to a photo
+avatar-load = Load
+avatar-drop-hint = Or drop an image file onto someone in the list.
+avatar-read-failed = Couldn't read the image: { $error }
+avatar-store-failed = Couldn't store the image: { $error }
+fixed-heading = Fixed Pairs
+fixed-description = Pairs that are decided before the wheel is spun.
+fixed-giver = Giver
+fixed-receiver = Receiver
+fixed-add = Add Pair
+fixed-pick-both = Pick both a giver and a receiver
+fixed-self = Someone can't give to themself
+fixed-already-giving = { $name } is already giving to someone
+fixed-already-receiving = { $name } is already receiving from someone
+fixed-impossible = With this pair it isn't possible to assign everyone else
+
+## Wheel page
+order-in-order = In order
+order-random = Random order
+order-chosen = Organizer chooses
+find-receivers = A receiver for each giver
+find-givers = A giver for each receiver
+wheel-spin-order = Spin order
+wheel-spin-to-find = Spin to find
+wheel-remaining-givers = Remaining Givers
+wheel-remaining-receivers = Remaining Receivers
+wheel-results = Results
+wheel-fixed = (fixed)
+wheel-already-spun = { $name } has already been spun for
+wheel-impossible = It isn't possible to assign everyone
+wheel-unfixable = Already drawn, so these pairs couldn't be fixed: { $pairs }
+wheel-pick-next = Pick who spins next from { $list }
+wheel-no-one-left = No one left to assign
+wheel-all-drawn = All names drawn
+wheel-spin = Spin Wheel
+wheel-draw-all = Draw All Now
+wheel-draw-all-hint = Draw everyone left without spinning
+wheel-next-spin = Next Spin
+wheel-fast-forward = Fast-forward
+wheel-restart = Restart
+wheel-undo = Undo
+wheel-undo-hint = Put the last result back in the hat
+wheel-present = Present
+wheel-present-hint = Fill the screen with the wheel. { $spin } spins, { $next } moves on, Esc exits.
+wheel-legend = Key
+wheel-description-empty = Gift exchange wheel, empty
+wheel-description-one = Gift exchange wheel with 1 name: { $names }
+wheel-description = Gift exchange wheel with { $count } names: { $names }
+status-giving = { $giver } is giving to { $receiver }
+status-buying = { $giver } is buying for { $receiver }
+status-spinning-for = Spinning for { $name }
+status-who-buys = Who is buying for { $name }?
+present-up-next = Up next: { $name }
+present-whos-next = Who's next?
+present-spin-hint = Press { $shortcut } to spin
+present-next-hint = Press { $shortcut } for the next spin
+present-exit = Exit (Esc)
+sync-changed = The people list has changed since the draw started: { $added } added, { $removed } removed
+sync-reordered = People have been moved around in the list since the draw started
+sync-fixed-pair = { $sign } fixed pair
+sync-update = Update Wheel
+sync-update-hint = Keeps existing results, except pairs with someone who was removed
+sync-wait = Wait for the current spin to finish
+sync-or-restart = or Restart to start the draw over
+
+## Settings page
+settings-preview = Preview
+preview-spin = Preview Spin
+preview-stop = Stop Preview
+settings-language = Language
+settings-theme = Theme
+theme-light = Light
+theme-dark = Dark
+theme-system = Same as system
+settings-wheel-colors = Wheel Colors
+palette-classic = Classic
+palette-festive = Festive
+palette-winter = Winter
+palette-color-blind-safe = Color-blind safe
+palette-custom = Custom
+colors-add = Add Color
+settings-wedge-sizes = Wedge Sizes
+wedges-equal = All the same size
+wedges-by-chances = Bigger for people more spins could land on
+wedges-only-possible = Hide people the current spin can't land on
+settings-narrow = Before each spin, shrink away the people it can't land on
+settings-wheel-labels = Wheel Labels
+settings-curved = Curve names along the rim
+settings-short-labels = When a name doesn't fit, show:
+short-initials = Initials
+short-numbers = Numbers
+settings-effects = Effects
+settings-sound = Play sound effects
+settings-sound-not-built = This build has no sound. Native builds need the `sound` feature, see the README.
+settings-sound-no-device = Sound couldn't be played, as no audio device could be opened.
+settings-confetti = Confetti when the wheel stops
+settings-spin = Spin
+spin-presets = Presets:
+spin-quick = Quick
+spin-standard = Standard
+spin-dramatic = Dramatic
+spin-wind-up = Wind up
+spin-full-speed = Full speed
+spin-slow-down = Slow down
+spin-top-speed = Top speed
+spin-curve = Speed curve
+easing-linear = Linear
+easing-ease-out-cubic = Ease-out cubic
+easing-ease-in-out-cubic = Ease-in-out cubic
+spin-idle-drift = Turn slowly between spins
+spin-total-time = Each spin takes about { $seconds } s
+settings-shortcuts = Keyboard Shortcuts
+settings-press-key = Press a key…
+settings-shortcut-hint = Click, then press the new shortcut. Esc cancels.
+settings-clear = Clear
+settings-reset-shortcuts = Reset shortcuts
+
+## Keyboard shortcuts
+action-show-people = Go to People
+action-show-wheel = Go to Wheel
+action-show-settings = Go to Settings
+action-show-about = Go to About
+action-add-person = Add a person
+action-spin = Spin the wheel
+action-next-spin = Next spin
+action-undo = Undo the last result
+action-restart = Restart the draw
+action-present = Presentation mode
+action-cheat-sheet = Show these shortcuts
+shortcut-none = None
+shortcuts-title = Keyboard Shortcuts
+shortcuts-hint = Tab moves between buttons and fields. Shortcuts can be changed in Settings.
+
+## Confirmations
+confirm-cancel = Cancel
+confirm-copy = Copy to clipboard first
+confirm-copy-hint = Keep a copy of what's about to be lost
+confirm-restart-title = Restart the draw?
+confirm-restart-pairs = { $drawn } of { $total } pairs drawn will be cleared
+confirm-remove-title = Remove { $name }?
+confirm-remove = Remove
+confirm-remove-person = { $name } will be taken off the list
+confirm-remove-fixed = The fixed pair { $giver } ==> { $receiver } will be removed
+confirm-remove-picture = Their picture will be lost
+
+## Bulk entry
+bulk-open = Add Many…
+bulk-title = Add Many People
+bulk-help = One person per line as "Name, Group", or a whole group as "Group: Name, Name". Tabs work too.
+bulk-add = Add { $count } people
+bulk-errors-kept = Lines with problems stay in the box to fix.
+bulk-contacts-skipped = Left out { $count } contacts:
+import-duplicate = { $name } ({ $group }) is already on the list
+import-missing-group = There's no group before the ":"
+import-no-names = No names after "{ $group }:"
+import-no-group = No group for { $name }, write it as "{ $name }, Group"
+import-too-many = Too many commas, write one person as "Name, Group"
+
+## Ordering
+people-search = Search
+people-sort = Sort by:
+people-sort-name = Name
+people-sort-group = Group
+people-order-hint = Names are spun for in this order. Drag ☰ to move someone.
+people-drag-hint = Drag to move
+
+## Contacts
+person-email = Email:
+person-optional = optional
+bulk-contacts-help = Contacts exported as a .vcf file can be pasted in, opened, or dropped onto the page.
+bulk-contacts-path = Path to a .vcf file
+bulk-open-file = Open
+vcard-group-from = Group from
+vcard-family-name = Family name
+vcard-organization = Organization
+vcard-category = First category
+vcard-no-group = { $name } has no { $field } to use as a group
+
+## Calendar
+calendar-open = Add to Calendar…
+calendar-title = Add to Calendar
+calendar-name = Exchange:
+calendar-default-name = Gift Exchange
+calendar-date = Date:
+calendar-location = Where:
+calendar-notes = Notes:
+calendar-notes-hint = Spending limit, theme, …
+calendar-bad-date = Write the date as YYYY-MM-DD, like 2024-12-24
+calendar-reminders = Remind each giver to buy their gift
+calendar-days-before = days before the exchange
+calendar-reminders-for = Reminders for
+calendar-everyone = Everyone
+calendar-reminders-hint = Pick one giver to make a file for them alone, so no one else's result is in it.
+calendar-partial = Only the { $count } pairs drawn so far are included, { $remaining } are still to draw.
+calendar-save = Save Calendar
+calendar-needs-date = Fill in the date first
+calendar-saved = Saved { $path }
+save-failed = Couldn't save { $path }: { $error }
+calendar-buy-gift = Buy a gift for { $receiver }
+calendar-buy-gift-description = { $giver } is giving to { $receiver } at { $event } on { $date }.
+
+## Printing
+print-open = Print…
+print-title = Print Results
+print-help = Saves a page to open in a browser and print.
+print-results = Table of who gives to whom
+print-tags = A gift tag to cut out for each receiver
+print-event-hint = The exchange name, date and place come from Add to Calendar.
+print-save = Save Page
+print-saved = Saved { $path }, open it in a browser to print
+print-hint = Print this page from the browser. The gift tags start on a new page, cut along the dashed lines.
+print-tag-to = To: { $name }
+print-tag-from = From: your Secret Santa
+
+## Sharing
+share-button = Share Link…
+share-title = Share Link
+share-help = Anyone opening this link in their browser gets this list of people and fixed pairs.
+share-address = Web app address:
+share-needs-address = Fill in where the web version of the app is to make a link.
+share-copy = Copy Link
+share-length = { $length } characters
+share-left-out = Photos and email addresses aren't included.
+share-too-long = Some apps cut off links this long. If it doesn't open, try sending it a different way.
+share-open-title = Open the shared setup?
+share-open = Open
+share-open-replaces = It replaces the { $people } people and { $pairs } fixed pairs here now
+share-bad-link = This link doesn't contain a setup the app can read. It may have been cut off when it was sent.
+share-newer-version = This link was made by a newer version of the app. Try reloading the page.
+share-bad-line = Line { $line } of the shared setup can't be read
+share-received = Sent a link? Paste it here to open it:
+share-paste-hint = Link
@@ -0,0 +1,253 @@
+# Español
+
+## Navigation
+app-title = Ruleta del Amigo Invisible
+nav-people = Personas
+nav-wheel = Ruleta
+nav-settings = Ajustes
+nav-about = Acerca de
+nav-shortcuts-hint = Atajos de teclado ({ $shortcut })
+about-powered-by = hecho con
+about-and = y
+
+## People page
+person-name = Nombre:
+person-group = Grupo:
+people-picture = Imagen:
+people-add = Añadir
+people-drop-hint = Suelta la imagen sobre alguien para usarla como su imagen.
+open-failed = No se pudo abrir { $path }: { $error }
+avatar-menu = Imagen…
+avatar-none = Sin imagen
+avatar-path-hint = Ruta a una foto
+avatar-load = Cargar
+avatar-drop-hint = O suelta un archivo de imagen sobre alguien de la lista.
+avatar-read-failed = No se pudo leer la imagen: { $error }
+avatar-store-failed = No se pudo guardar la imagen: { $error }
+fixed-heading = Parejas fijas
+fixed-description = Parejas decididas antes de girar la ruleta.
+fixed-giver = Quien regala
+fixed-receiver = Quien recibe
+fixed-add = Añadir pareja
+fixed-pick-both = Elige quién regala y quién recibe
+fixed-self = Nadie puede regalarse a sí mismo
+fixed-already-giving = { $name } ya le regala a alguien
+fixed-already-receiving = { $name } ya recibe de alguien
+fixed-impossible = Con esta pareja no es posible asignar a todos los demás
+
+## Wheel page
+order-in-order = En orden
+order-random = Orden aleatorio
+order-chosen = Elige el organizador
+find-receivers = Quién recibe de cada persona
+find-givers = Quién regala a cada persona
+wheel-spin-order = Orden de giro
+wheel-spin-to-find = Girar para encontrar
+wheel-remaining-givers = Faltan por regalar
+wheel-remaining-receivers = Faltan por recibir
+wheel-results = Resultados
+wheel-fixed = (fija)
+wheel-already-spun = Ya se giró la ruleta para { $name }
+wheel-impossible = No es posible asignar a todos
+wheel-unfixable = Ya sorteados, así que estas parejas no se pudieron fijar: { $pairs }
+wheel-pick-next = Elige quién gira ahora en { $list }
+wheel-no-one-left = No queda nadie por asignar
+wheel-all-drawn = Todos los nombres sorteados
+wheel-spin = Girar la ruleta
+wheel-draw-all = Sortear todo ya
+wheel-draw-all-hint = Sortea a todos los que quedan sin girar
+wheel-next-spin = Siguiente giro
+wheel-fast-forward = Avanzar
+wheel-restart = Reiniciar
+wheel-undo = Deshacer
+wheel-undo-hint = Devuelve el último resultado al sombrero
+wheel-present = Presentar
+wheel-present-hint = Muestra la ruleta a pantalla completa. { $spin } gira, { $next } avanza, Esc sale.
+wheel-legend = Leyenda
+wheel-description-empty = Ruleta del amigo invisible, vacía
+wheel-description-one = Ruleta del amigo invisible con 1 nombre: { $names }
+wheel-description = Ruleta del amigo invisible con { $count } nombres: { $names }
+status-giving = { $giver } le regala a { $receiver }
+status-buying = { $giver } le compra a { $receiver }
+status-spinning-for = Girando para { $name }
+status-who-buys = ¿Quién le compra a { $name }?
+present-up-next = Ahora: { $name }
+present-whos-next = ¿Quién sigue?
+present-spin-hint = Pulsa { $shortcut } para girar
+present-next-hint = Pulsa { $shortcut } para el siguiente giro
+present-exit = Salir (Esc)
+sync-changed = La lista de personas ha cambiado desde que empezó el sorteo: { $added } añadidas, { $removed } quitadas
+sync-reordered = Se cambió el orden de la lista desde que empezó el sorteo
+sync-fixed-pair = { $sign } pareja fija
+sync-update = Actualizar ruleta
+sync-update-hint = Conserva los resultados, salvo las parejas con alguien que se quitó
+sync-wait = Espera a que termine el giro actual
+sync-or-restart = o Reiniciar para empezar el sorteo de nuevo
+
+## Settings page
+settings-preview = Vista previa
+preview-spin = Probar giro
+preview-stop = Detener
+settings-language = Idioma
+settings-theme = Tema
+theme-light = Claro
+theme-dark = Oscuro
+theme-system = Igual que el sistema
+settings-wheel-colors = Colores de la ruleta
+palette-classic = Clásico
+palette-festive = Festivo
+palette-winter = Invierno
+palette-color-blind-safe = Apto para daltonismo
+palette-custom = Personalizado
+colors-add = Añadir color
+settings-wedge-sizes = Tamaño de las porciones
+wedges-equal = Todas del mismo tamaño
+wedges-by-chances = Más grandes para quien puede tocar en más giros
+wedges-only-possible = Ocultar a quien no puede tocar en este giro
+settings-narrow = Antes de cada giro, encoger a quien no puede tocar
+settings-wheel-labels = Nombres en la ruleta
+settings-curved = Curvar los nombres por el borde
+settings-short-labels = Si un nombre no cabe, mostrar:
+short-initials = Iniciales
+short-numbers = Números
+settings-effects = Efectos
+settings-sound = Reproducir efectos de sonido
+settings-sound-not-built = Esta compilación no tiene sonido. Las compilaciones nativas necesitan la función `sound`, consulta el README.
+settings-sound-no-device = No se pudo reproducir sonido porque no se pudo abrir ningún dispositivo de audio.
+settings-confetti = Confeti cuando la ruleta se detiene
+settings-spin = Giro
+spin-presets = Ajustes rápidos:
+spin-quick = Rápido
+spin-standard = Normal
+spin-dramatic = Dramático
+spin-wind-up = Arranque
+spin-full-speed = A toda velocidad
+spin-slow-down = Frenado
+spin-top-speed = Velocidad máxima
+spin-curve = Curva de velocidad
+easing-linear = Lineal
+easing-ease-out-cubic = Cúbica de salida
+easing-ease-in-out-cubic = Cúbica de entrada y salida
+spin-idle-drift = Girar despacio entre giros
+spin-total-time = Cada giro dura unos { $seconds } s
+settings-shortcuts = Atajos de teclado
+settings-press-key = Pulsa una tecla…
+settings-shortcut-hint = Haz clic y pulsa el nuevo atajo. Esc cancela.
+settings-clear = Quitar
+settings-reset-shortcuts = Restablecer atajos
+
+## Keyboard shortcuts
+action-show-people = Ir a Personas
+action-show-wheel = Ir a Ruleta
+action-show-settings = Ir a Ajustes
+action-show-about = Ir a Acerca de
+action-add-person = Añadir una persona
+action-spin = Girar la ruleta
+action-next-spin = Siguiente giro
+action-undo = Deshacer el último resultado
+action-restart = Reiniciar el sorteo
+action-present = Modo presentación
+action-cheat-sheet = Mostrar estos atajos
+shortcut-none = Ninguno
+shortcuts-title = Atajos de teclado
+shortcuts-hint = Tab pasa entre botones y campos. Los atajos se cambian en Ajustes.
+
+## Confirmations
+confirm-cancel = Cancelar
+confirm-copy = Copiar al portapapeles antes
+confirm-copy-hint = Guarda una copia de lo que se va a perder
+confirm-restart-title = ¿Reiniciar el sorteo?
+confirm-restart-pairs = Se borrarán { $drawn } de { $total } parejas sorteadas
+confirm-remove-title = ¿Quitar a { $name }?
+confirm-remove = Quitar
+confirm-remove-person = { $name } saldrá de la lista
+confirm-remove-fixed = Se quitará la pareja fija { $giver } ==> { $receiver }
+confirm-remove-picture = Se perderá su imagen
+
+## Bulk entry
+bulk-open = Añadir varias…
+bulk-title = Añadir varias personas
+bulk-help = Una persona por línea como "Nombre, Grupo", o un grupo entero como "Grupo: Nombre, Nombre". También valen tabuladores.
+bulk-add = Añadir { $count } personas
+bulk-errors-kept = Las líneas con problemas se quedan en el cuadro para corregirlas.
+bulk-contacts-skipped = Se omitieron { $count } contactos:
+import-duplicate = { $name } ({ $group }) ya está en la lista
+import-missing-group = Falta el grupo antes de ":"
+import-no-names = No hay nombres después de "{ $group }:"
+import-no-group = Falta el grupo de { $name }, escríbelo como "{ $name }, Grupo"
+import-too-many = Demasiadas comas, escribe a cada persona como "Nombre, Grupo"
+
+## Ordering
+people-search = Buscar
+people-sort = Ordenar por:
+people-sort-name = Nombre
+people-sort-group = Grupo
+people-order-hint = Se gira para los nombres en este orden. Arrastra ☰ para mover a alguien.
+people-drag-hint = Arrastra para mover
+
+## Contacts
+person-email = Correo:
+person-optional = opcional
+bulk-contacts-help = Los contactos exportados como archivo .vcf se pueden pegar, abrir o soltar sobre la página.
+bulk-contacts-path = Ruta a un archivo .vcf
+bulk-open-file = Abrir
+vcard-group-from = Grupo según
+vcard-family-name = Apellido
+vcard-organization = Organización
+vcard-category = Primera categoría
+vcard-no-group = { $name } no tiene { $field } que usar como grupo
+
+## Calendar
+calendar-open = Añadir al calendario…
+calendar-title = Añadir al calendario
+calendar-name = Intercambio:
+calendar-default-name = Amigo Invisible
+calendar-date = Fecha:
+calendar-location = Lugar:
+calendar-notes = Notas:
+calendar-notes-hint = Presupuesto, temática, …
+calendar-bad-date = Escribe la fecha como AAAA-MM-DD, por ejemplo 2024-12-24
+calendar-reminders = Recordar a cada persona que compre su regalo
+calendar-days-before = días antes del intercambio
+calendar-reminders-for = Recordatorios para
+calendar-everyone = Todos
+calendar-reminders-hint = Elige a una persona para crear un archivo solo para ella, sin los resultados de nadie más.
+calendar-partial = Solo se incluyen las { $count } parejas sorteadas hasta ahora, faltan { $remaining } por sortear.
+calendar-save = Guardar calendario
+calendar-needs-date = Primero escribe la fecha
+calendar-saved = Guardado { $path }
+save-failed = No se pudo guardar { $path }: { $error }
+calendar-buy-gift = Comprar un regalo para { $receiver }
+calendar-buy-gift-description = { $giver } le regala a { $receiver } en { $event } el { $date }.
+
+## Printing
+print-open = Imprimir…
+print-title = Imprimir resultados
+print-help = Guarda una página para abrirla en un navegador e imprimirla.
+print-results = Tabla de quién regala a quién
+print-tags = Una etiqueta de regalo para recortar por cada persona que recibe
+print-event-hint = El nombre, la fecha y el lugar del intercambio se toman de Añadir al calendario.
+print-save = Guardar página
+print-saved = Guardado { $path }, ábrelo en un navegador para imprimirlo
+print-hint = Imprime esta página desde el navegador. Las etiquetas empiezan en una página nueva, recórtalas por las líneas discontinuas.
+print-tag-to = Para: { $name }
+print-tag-from = De: tu amigo invisible
+
+## Sharing
+share-button = Compartir enlace…
+share-title = Compartir enlace
+share-help = Quien abra este enlace en su navegador tendrá esta lista de personas y parejas fijas.
+share-address = Dirección de la app web:
+share-needs-address = Escribe dónde está la versión web de la app para crear un enlace.
+share-copy = Copiar enlace
+share-length = { $length } caracteres
+share-left-out = No se incluyen fotos ni direcciones de correo.
+share-too-long = Algunas apps cortan enlaces tan largos. Si no se abre, prueba a enviarlo de otra forma.
+share-open-title = ¿Abrir la configuración compartida?
+share-open = Abrir
+share-open-replaces = Sustituye a las { $people } personas y { $pairs } parejas fijas que hay ahora
+share-bad-link = Este enlace no contiene una configuración que la app pueda leer. Puede que se cortara al enviarlo.
+share-newer-version = Este enlace se creó con una versión más nueva de la app. Prueba a recargar la página.
+share-bad-line = No se puede leer la línea { $line } de la configuración compartida
+share-received = ¿Te han enviado un enlace? Pégalo aquí para abrirlo:
+share-paste-hint = Enlace
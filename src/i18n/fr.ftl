@@ -0,0 +1,253 @@
+# Français
+
+## Navigation
+app-title = Roue du Père Noël secret
+nav-people = Participants
+nav-wheel = Roue
+nav-settings = Réglages
+nav-about = À propos
+nav-shortcuts-hint = Raccourcis clavier ({ $shortcut })
+about-powered-by = propulsé par
+about-and = et
+
+## People page
+person-name = Nom :
+person-group = Groupe :
+people-picture = Image :
+people-add = Ajouter
+people-drop-hint = Déposez l'image sur quelqu'un pour en faire son image.
+open-failed = Impossible d'ouvrir { $path } : { $error }
+avatar-menu = Image…
+avatar-none = Pas d'image
+avatar-path-hint = Chemin vers une photo
+avatar-load = Charger
+avatar-drop-hint = Ou déposez un fichier image sur quelqu'un de la liste.
+avatar-read-failed = Impossible de lire l'image : { $error }
+avatar-store-failed = Impossible d'enregistrer l'image : { $error }
+fixed-heading = Paires fixées
+fixed-description = Paires décidées avant de faire tourner la roue.
+fixed-giver = Qui offre
+fixed-receiver = Qui reçoit
+fixed-add = Ajouter la paire
+fixed-pick-both = Choisissez qui offre et qui reçoit
+fixed-self = Personne ne peut s'offrir un cadeau à soi-même
+fixed-already-giving = { $name } offre déjà à quelqu'un
+fixed-already-receiving = { $name } reçoit déjà de quelqu'un
+fixed-impossible = Avec cette paire, il n'est plus possible d'attribuer tout le monde
+
+## Wheel page
+order-in-order = Dans l'ordre
+order-random = Ordre aléatoire
+order-chosen = L'organisateur choisit
+find-receivers = Un destinataire pour chaque donneur
+find-givers = Un donneur pour chaque destinataire
+wheel-spin-order = Ordre des tours
+wheel-spin-to-find = Tourner pour trouver
+wheel-remaining-givers = Donneurs restants
+wheel-remaining-receivers = Destinataires restants
+wheel-results = Résultats
+wheel-fixed = (fixée)
+wheel-already-spun = La roue a déjà tourné pour { $name }
+wheel-impossible = Impossible d'attribuer tout le monde
+wheel-unfixable = Déjà tirées, ces paires n'ont pas pu être fixées : { $pairs }
+wheel-pick-next = Choisissez qui tourne ensuite parmi les { $list }
+wheel-no-one-left = Plus personne à attribuer
+wheel-all-drawn = Tous les noms sont tirés
+wheel-spin = Tourner la roue
+wheel-draw-all = Tout tirer maintenant
+wheel-draw-all-hint = Tirer tous les restants sans tourner
+wheel-next-spin = Tour suivant
+wheel-fast-forward = Avance rapide
+wheel-restart = Recommencer
+wheel-undo = Annuler
+wheel-undo-hint = Remettre le dernier résultat dans le chapeau
+wheel-present = Présenter
+wheel-present-hint = Affiche la roue en plein écran. { $spin } fait tourner, { $next } passe au suivant, Échap quitte.
+wheel-legend = Légende
+wheel-description-empty = Roue du Père Noël secret, vide
+wheel-description-one = Roue du Père Noël secret avec 1 nom : { $names }
+wheel-description = Roue du Père Noël secret avec { $count } noms : { $names }
+status-giving = { $giver } offre à { $receiver }
+status-buying = { $giver } achète pour { $receiver }
+status-spinning-for = La roue tourne pour { $name }
+status-who-buys = Qui achète pour { $name } ?
+present-up-next = Au tour de : { $name }
+present-whos-next = À qui le tour ?
+present-spin-hint = Appuyez sur { $shortcut } pour tourner
+present-next-hint = Appuyez sur { $shortcut } pour le tour suivant
+present-exit = Quitter (Échap)
+sync-changed = La liste des participants a changé depuis le début du tirage : { $added } ajoutés, { $removed } retirés
+sync-reordered = L'ordre de la liste a changé depuis le début du tirage
+sync-fixed-pair = { $sign } paire fixée
+sync-update = Mettre à jour la roue
+sync-update-hint = Garde les résultats, sauf les paires avec quelqu'un qui a été retiré
+sync-wait = Attendez la fin du tour en cours
+sync-or-restart = ou Recommencer pour refaire le tirage
+
+## Settings page
+settings-preview = Aperçu
+preview-spin = Essayer un tour
+preview-stop = Arrêter l'aperçu
+settings-language = Langue
+settings-theme = Thème
+theme-light = Clair
+theme-dark = Sombre
+theme-system = Comme le système
+settings-wheel-colors = Couleurs de la roue
+palette-classic = Classique
+palette-festive = Festive
+palette-winter = Hiver
+palette-color-blind-safe = Adaptée au daltonisme
+palette-custom = Personnalisée
+colors-add = Ajouter une couleur
+settings-wedge-sizes = Taille des parts
+wedges-equal = Toutes de la même taille
+wedges-by-chances = Plus grandes pour ceux sur qui plus de tours peuvent tomber
+wedges-only-possible = Masquer ceux sur qui ce tour ne peut pas tomber
+settings-narrow = Avant chaque tour, réduire ceux sur qui il ne peut pas tomber
+settings-wheel-labels = Noms sur la roue
+settings-curved = Écrire les noms le long du bord
+settings-short-labels = Quand un nom ne tient pas, afficher :
+short-initials = Initiales
+short-numbers = Numéros
+settings-effects = Effets
+settings-sound = Jouer les effets sonores
+settings-sound-not-built = Cette version n'a pas de son. Les versions natives ont besoin de la fonctionnalité `sound`, voir le README.
+settings-sound-no-device = Impossible de jouer le son, aucun périphérique audio n'a pu être ouvert.
+settings-confetti = Confettis quand la roue s'arrête
+settings-spin = Rotation
+spin-presets = Préréglages :
+spin-quick = Rapide
+spin-standard = Normal
+spin-dramatic = Dramatique
+spin-wind-up = Démarrage
+spin-full-speed = Pleine vitesse
+spin-slow-down = Ralentissement
+spin-top-speed = Vitesse maximale
+spin-curve = Courbe de vitesse
+easing-linear = Linéaire
+easing-ease-out-cubic = Cubique en sortie
+easing-ease-in-out-cubic = Cubique en entrée et sortie
+spin-idle-drift = Tourner lentement entre les tours
+spin-total-time = Chaque tour dure environ { $seconds } s
+settings-shortcuts = Raccourcis clavier
+settings-press-key = Appuyez sur une touche…
+settings-shortcut-hint = Cliquez, puis appuyez sur le nouveau raccourci. Échap annule.
+settings-clear = Effacer
+settings-reset-shortcuts = Réinitialiser les raccourcis
+
+## Keyboard shortcuts
+action-show-people = Aller aux participants
+action-show-wheel = Aller à la roue
+action-show-settings = Aller aux réglages
+action-show-about = Aller à À propos
+action-add-person = Ajouter un participant
+action-spin = Tourner la roue
+action-next-spin = Tour suivant
+action-undo = Annuler le dernier résultat
+action-restart = Recommencer le tirage
+action-present = Mode présentation
+action-cheat-sheet = Afficher ces raccourcis
+shortcut-none = Aucun
+shortcuts-title = Raccourcis clavier
+shortcuts-hint = Tab passe d'un bouton ou d'un champ à l'autre. Les raccourcis se modifient dans les réglages.
+
+## Confirmations
+confirm-cancel = Annuler
+confirm-copy = Copier d'abord dans le presse-papiers
+confirm-copy-hint = Garder une copie de ce qui va être perdu
+confirm-restart-title = Recommencer le tirage ?
+confirm-restart-pairs = { $drawn } paires tirées sur { $total } seront effacées
+confirm-remove-title = Retirer { $name } ?
+confirm-remove = Retirer
+confirm-remove-person = { $name } sera retiré de la liste
+confirm-remove-fixed = La paire fixée { $giver } ==> { $receiver } sera supprimée
+confirm-remove-picture = Son image sera perdue
+
+## Bulk entry
+bulk-open = En ajouter plusieurs…
+bulk-title = Ajouter plusieurs participants
+bulk-help = Une personne par ligne sous la forme "Nom, Groupe", ou un groupe entier sous la forme "Groupe : Nom, Nom". Les tabulations marchent aussi.
+bulk-add = Ajouter { $count } participants
+bulk-errors-kept = Les lignes avec des problèmes restent dans la zone pour être corrigées.
+bulk-contacts-skipped = { $count } contacts laissés de côté :
+import-duplicate = { $name } ({ $group }) est déjà dans la liste
+import-missing-group = Il manque le groupe avant le ":"
+import-no-names = Aucun nom après "{ $group } :"
+import-no-group = Pas de groupe pour { $name }, écrivez "{ $name }, Groupe"
+import-too-many = Trop de virgules, écrivez une personne sous la forme "Nom, Groupe"
+
+## Ordering
+people-search = Rechercher
+people-sort = Trier par :
+people-sort-name = Nom
+people-sort-group = Groupe
+people-order-hint = La roue tourne pour les noms dans cet ordre. Faites glisser ☰ pour déplacer quelqu'un.
+people-drag-hint = Glisser pour déplacer
+
+## Contacts
+person-email = E-mail :
+person-optional = facultatif
+bulk-contacts-help = Les contacts exportés en fichier .vcf peuvent être collés, ouverts ou déposés sur la page.
+bulk-contacts-path = Chemin vers un fichier .vcf
+bulk-open-file = Ouvrir
+vcard-group-from = Groupe d'après
+vcard-family-name = Nom de famille
+vcard-organization = Organisation
+vcard-category = Première catégorie
+vcard-no-group = { $name } n'a pas de champ « { $field } » à utiliser comme groupe
+
+## Calendar
+calendar-open = Ajouter au calendrier…
+calendar-title = Ajouter au calendrier
+calendar-name = Échange :
+calendar-default-name = Échange de cadeaux
+calendar-date = Date :
+calendar-location = Lieu :
+calendar-notes = Notes :
+calendar-notes-hint = Budget, thème, …
+calendar-bad-date = Écrivez la date sous la forme AAAA-MM-JJ, par exemple 2024-12-24
+calendar-reminders = Rappeler à chacun d'acheter son cadeau
+calendar-days-before = jours avant l'échange
+calendar-reminders-for = Rappels pour
+calendar-everyone = Tout le monde
+calendar-reminders-hint = Choisissez une personne pour créer un fichier rien que pour elle, sans les résultats des autres.
+calendar-partial = Seules les { $count } paires tirées jusqu'ici sont incluses, il en reste { $remaining } à tirer.
+calendar-save = Enregistrer le calendrier
+calendar-needs-date = Indiquez d'abord la date
+calendar-saved = { $path } enregistré
+save-failed = Impossible d'enregistrer { $path } : { $error }
+calendar-buy-gift = Acheter un cadeau pour { $receiver }
+calendar-buy-gift-description = { $giver } offre un cadeau à { $receiver } pour { $event } le { $date }.
+
+## Printing
+print-open = Imprimer…
+print-title = Imprimer les résultats
+print-help = Enregistre une page à ouvrir dans un navigateur pour l'imprimer.
+print-results = Tableau de qui offre à qui
+print-tags = Une étiquette cadeau à découper pour chaque destinataire
+print-event-hint = Le nom, la date et le lieu de l'échange viennent d'Ajouter au calendrier.
+print-save = Enregistrer la page
+print-saved = { $path } enregistré, ouvrez-le dans un navigateur pour l'imprimer
+print-hint = Imprimez cette page depuis le navigateur. Les étiquettes commencent sur une nouvelle page, découpez-les le long des pointillés.
+print-tag-to = Pour : { $name }
+print-tag-from = De : votre Père Noël secret
+
+## Sharing
+share-button = Partager un lien…
+share-title = Partager un lien
+share-help = Quiconque ouvre ce lien dans son navigateur obtient cette liste de personnes et de paires fixes.
+share-address = Adresse de l'application web :
+share-needs-address = Indiquez où se trouve la version web de l'application pour créer un lien.
+share-copy = Copier le lien
+share-length = { $length } caractères
+share-left-out = Les photos et adresses e-mail ne sont pas incluses.
+share-too-long = Certaines applications coupent les liens aussi longs. S'il ne s'ouvre pas, essayez de l'envoyer autrement.
+share-open-title = Ouvrir la configuration partagée ?
+share-open = Ouvrir
+share-open-replaces = Elle remplace les { $people } personnes et { $pairs } paires fixes actuelles
+share-bad-link = Ce lien ne contient pas de configuration lisible par l'application. Il a peut-être été coupé à l'envoi.
+share-newer-version = Ce lien a été créé par une version plus récente de l'application. Essayez de recharger la page.
+share-bad-line = La ligne { $line } de la configuration partagée est illisible
+share-received = Vous avez reçu un lien ? Collez-le ici pour l'ouvrir :
+share-paste-hint = Lien
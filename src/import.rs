@@ -0,0 +1,246 @@
+//! Reading lists of people pasted in as text, so a whole family can be added at once.
+//!
+//! Each line is either one person, `Alice, Smith`, or a whole group, `Smith: Alice, Bob`. Tabs
+//! work in place of commas, so columns copied from a spreadsheet can be pasted straight in.
+//! Blank lines and lines starting with `#` are skipped. Contacts exported as vCards can be
+//! pasted in too, see [`vcard`].
+
+use crate::hat::Person;
+
+use self::vcard::GroupField;
+
+pub(crate) mod vcard;
+
+/// What one line of pasted text turned into.
+pub(crate) struct Line {
+    /// Counting from 1, as an editor would show it.
+    pub(crate) number: usize,
+    /// What to keep of the line when it has problems: all of it, or only the people turned away
+    /// if others on it were added.
+    pub(crate) text: String,
+    pub(crate) people: Vec<Person>,
+    pub(crate) errors: Vec<String>,
+}
+
+/// Reads people from `text`, checking each against `existing` and everyone on earlier lines.
+/// For vCards, each card is a line and `group_field` says where to find their group.
+pub(crate) fn parse_people(text: &str, group_field: GroupField, existing: &[Person]) -> Vec<Line> {
+    if vcard::is_vcard(text) {
+        return from_contacts(text, group_field, existing);
+    }
+
+    let mut seen: Vec<Person> = existing.to_vec();
+    let mut lines = Vec::new();
+    for (idx, raw) in text.lines().enumerate() {
+        let trimmed = raw.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let mut line = Line {
+            number: idx + 1,
+            text: raw.to_owned(),
+            people: Vec::new(),
+            errors: Vec::new(),
+        };
+        let candidates = match parse_line(trimmed) {
+            Ok(candidates) => candidates,
+            Err(msg) => {
+                line.errors.push(msg);
+                lines.push(line);
+                continue;
+            }
+        };
+        let mut turned_away = Vec::new();
+        for person in candidates {
+            if !add_unless_seen(&mut line, person.clone(), &mut seen) {
+                turned_away.push(person.name);
+            }
+        }
+        // only lines for a whole group can be partly added, so the rest go back as one
+        if let (Some(added), false) = (line.people.first(), turned_away.is_empty()) {
+            line.text = format!("{}: {}", added.group, turned_away.join(", "));
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+fn from_contacts(text: &str, group_field: GroupField, existing: &[Person]) -> Vec<Line> {
+    let mut seen: Vec<Person> = existing.to_vec();
+    vcard::parse(text)
+        .into_iter()
+        .enumerate()
+        .map(|(idx, contact)| {
+            let mut line = Line {
+                number: idx + 1,
+                text: contact.name.clone(),
+                people: Vec::new(),
+                errors: Vec::new(),
+            };
+            let group = group_field.get(&contact);
+            if group.is_empty() {
+                line.errors.push(tr!(
+                    "vcard-no-group",
+                    name = contact.name,
+                    field = group_field.label()
+                ));
+            } else {
+                let mut person = Person::new(&contact.name, group);
+                person.email = contact.email;
+                add_unless_seen(&mut line, person, &mut seen);
+            }
+            line
+        })
+        .collect()
+}
+
+/// Adds `person` to the line unless they're already in `seen`. Returns whether they were added.
+fn add_unless_seen(line: &mut Line, person: Person, seen: &mut Vec<Person>) -> bool {
+    if seen.contains(&person) {
+        line.errors.push(tr!(
+            "import-duplicate",
+            name = person.name,
+            group = person.group
+        ));
+        false
+    } else {
+        seen.push(person.clone());
+        line.people.push(person);
+        true
+    }
+}
+
+fn parse_line(line: &str) -> Result<Vec<Person>, String> {
+    if let Some((group, names)) = line.split_once(':') {
+        let group = group.trim();
+        if group.is_empty() {
+            return Err(tr!("import-missing-group").into());
+        }
+        let names: Vec<_> = split_fields(names).collect();
+        if names.is_empty() {
+            return Err(tr!("import-no-names", group = group));
+        }
+        return Ok(names
+            .into_iter()
+            .map(|name| Person::new(name, group))
+            .collect());
+    }
+
+    let fields: Vec<_> = split_fields(line).collect();
+    match fields[..] {
+        [name, group] => Ok(vec![Person::new(name, group)]),
+        [name] => Err(tr!("import-no-group", name = name)),
+        _ => Err(tr!("import-too-many").into()),
+    }
+}
+
+/// Splits on commas or tabs, dropping empty fields.
+fn split_fields(text: &str) -> impl Iterator<Item = &str> {
+    text.split([',', '\t'])
+        .map(str::trim)
+        .filter(|field| !field.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn people(line: &str) -> Vec<(String, String)> {
+        parse_line(line)
+            .unwrap()
+            .into_iter()
+            .map(|person| (person.name, person.group))
+            .collect()
+    }
+
+    fn pair(name: &str, group: &str) -> (String, String) {
+        (name.into(), group.into())
+    }
+
+    #[test]
+    fn one_person() {
+        assert_eq!(people(" Alice , Smith "), [pair("Alice", "Smith")]);
+        assert_eq!(people("Alice\tSmith"), [pair("Alice", "Smith")]);
+    }
+
+    #[test]
+    fn whole_group() {
+        assert_eq!(
+            people("Smith: Alice, Bob,,\tCarol"),
+            [
+                pair("Alice", "Smith"),
+                pair("Bob", "Smith"),
+                pair("Carol", "Smith")
+            ]
+        );
+    }
+
+    #[test]
+    fn bad_lines() {
+        assert_eq!(
+            parse_line(": Alice").unwrap_err(),
+            tr!("import-missing-group")
+        );
+        assert_eq!(
+            parse_line("Smith: , ").unwrap_err(),
+            tr!("import-no-names", group = "Smith")
+        );
+        assert_eq!(
+            parse_line("Alice").unwrap_err(),
+            tr!("import-no-group", name = "Alice")
+        );
+        assert_eq!(
+            parse_line("Alice, Smith, Jones").unwrap_err(),
+            tr!("import-too-many")
+        );
+    }
+
+    #[test]
+    fn duplicates_and_comments() {
+        let existing = [Person::new("Alice", "Smith")];
+        let lines = parse_people(
+            "# the Smiths\nSmith: Alice, Bob\n\nBob, Smith\n",
+            GroupField::default(),
+            &existing,
+        );
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].number, 2);
+        assert_eq!(lines[0].people, [Person::new("Bob", "Smith")]);
+        assert_eq!(
+            lines[0].errors,
+            [tr!("import-duplicate", name = "Alice", group = "Smith")]
+        );
+        assert_eq!(lines[1].number, 4);
+        assert!(lines[1].people.is_empty());
+        assert_eq!(lines[1].errors.len(), 1);
+    }
+
+    #[test]
+    fn only_what_was_turned_away_is_kept() {
+        let existing = [Person::new("Alice", "Smith"), Person::new("Carol", "Smith")];
+        let text = "Smith: Alice, Bob, Carol\nAlice, Smith\nDave\n";
+        let lines = parse_people(text, GroupField::default(), &existing);
+        assert_eq!(lines[0].people, [Person::new("Bob", "Smith")]);
+        assert_eq!(lines[0].text, "Smith: Alice, Carol");
+        assert_eq!(lines[1].text, "Alice, Smith");
+        assert_eq!(lines[2].text, "Dave");
+    }
+
+    #[test]
+    fn contacts_without_a_group() {
+        let lines = parse_people(
+            "BEGIN:VCARD\nFN:Alice\nORG:Acme\nEND:VCARD\nBEGIN:VCARD\nFN:Bob\nEND:VCARD\n",
+            GroupField::Organization,
+            &[],
+        );
+        assert_eq!(lines[0].people, [Person::new("Alice", "Acme")]);
+        assert_eq!(
+            lines[1].errors,
+            [tr!(
+                "vcard-no-group",
+                name = "Bob",
+                field = GroupField::Organization.label()
+            )]
+        );
+    }
+}
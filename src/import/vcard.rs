@@ -0,0 +1,284 @@
+//! Reading contacts exported from a phone or address book as a `.vcf` file.
+//!
+//! Handles vCard 2.1, 3.0 and 4.0 as far as names, emails, organizations and categories go,
+//! including folded lines, escaped characters and the quoted-printable text older phones write.
+
+use serde::{Deserialize, Serialize};
+
+/// One contact card.
+#[derive(Debug, Default)]
+pub(crate) struct Contact {
+    /// The formatted name, or one put together from the name parts if there wasn't one.
+    pub(crate) name: String,
+    pub(crate) family_name: String,
+    pub(crate) email: String,
+    pub(crate) organization: String,
+    pub(crate) categories: Vec<String>,
+}
+
+/// Which part of a contact becomes the person's group.
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub(crate) enum GroupField {
+    #[default]
+    FamilyName,
+    Organization,
+    Category,
+}
+
+impl GroupField {
+    pub(crate) const ALL: [GroupField; 3] = [
+        GroupField::FamilyName,
+        GroupField::Organization,
+        GroupField::Category,
+    ];
+
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            GroupField::FamilyName => tr!("vcard-family-name"),
+            GroupField::Organization => tr!("vcard-organization"),
+            GroupField::Category => tr!("vcard-category"),
+        }
+    }
+
+    pub(crate) fn get(self, contact: &Contact) -> &str {
+        match self {
+            GroupField::FamilyName => &contact.family_name,
+            GroupField::Organization => &contact.organization,
+            GroupField::Category => contact.categories.first().map_or("", String::as_str),
+        }
+    }
+}
+
+/// Whether `text` looks like vCard data rather than a typed list of names.
+pub(crate) fn is_vcard(text: &str) -> bool {
+    // some address books start the file with a byte order mark
+    text.trim_start_matches('\u{feff}')
+        .trim_start()
+        .get(..11)
+        .map_or(false, |start| start.eq_ignore_ascii_case("BEGIN:VCARD"))
+}
+
+/// Reads every card in `text`. Cards without any name are skipped.
+pub(crate) fn parse(text: &str) -> Vec<Contact> {
+    let mut contacts = Vec::new();
+    let mut card: Option<(Contact, Vec<String>)> = None;
+    for line in unfold(text.trim_start_matches('\u{feff}')) {
+        let Some((name, params, value)) = split_property(&line) else {
+            continue;
+        };
+        // vCard 2.1 allows the parameter without the `ENCODING=`
+        let value = if params
+            .iter()
+            .any(|param| param.to_ascii_uppercase().ends_with("QUOTED-PRINTABLE"))
+        {
+            decode_quoted_printable(value)
+        } else {
+            value.to_owned()
+        };
+
+        match (name.as_str(), &mut card) {
+            ("BEGIN", None) if value.eq_ignore_ascii_case("VCARD") => {
+                card = Some((Contact::default(), Vec::new()));
+            }
+            ("END", Some(_)) if value.eq_ignore_ascii_case("VCARD") => {
+                if let Some((mut contact, name_parts)) = card.take() {
+                    if contact.name.is_empty() {
+                        contact.name = name_parts.join(" ");
+                    }
+                    if !contact.name.is_empty() {
+                        contacts.push(contact);
+                    }
+                }
+            }
+            ("FN", Some((contact, _))) => contact.name = unescape(&value).trim().to_owned(),
+            ("N", Some((contact, name_parts))) => {
+                // family; given; additional; prefix; suffix
+                let parts: Vec<_> = split_unescaped(&value, ';');
+                contact.family_name = parts.first().cloned().unwrap_or_default();
+                *name_parts = [1, 2, 0]
+                    .iter()
+                    .filter_map(|&idx| parts.get(idx))
+                    .filter(|part| !part.is_empty())
+                    .cloned()
+                    .collect();
+            }
+            ("EMAIL", Some((contact, _))) if contact.email.is_empty() => {
+                contact.email = unescape(&value).trim().to_owned();
+            }
+            ("ORG", Some((contact, _))) => {
+                contact.organization = split_unescaped(&value, ';')
+                    .into_iter()
+                    .next()
+                    .unwrap_or_default();
+            }
+            ("CATEGORIES", Some((contact, _))) => {
+                contact.categories.extend(
+                    split_unescaped(&value, ',')
+                        .into_iter()
+                        .filter(|category| !category.is_empty()),
+                );
+            }
+            _ => (),
+        }
+    }
+    contacts
+}
+
+/// Joins folded lines back up: a line starting with a space or tab continues the one before,
+/// as does the line after a quoted-printable value ending in `=`.
+fn unfold(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut soft_break = false;
+    for raw in text.lines() {
+        match lines.last_mut() {
+            Some(last) if raw.starts_with([' ', '\t']) => last.push_str(&raw[1..]),
+            Some(last) if soft_break => {
+                last.pop();
+                last.push_str(raw);
+            }
+            _ => lines.push(raw.to_owned()),
+        }
+        let last = lines.last().map_or("", String::as_str);
+        soft_break = last.ends_with('=') && last.to_ascii_uppercase().contains("QUOTED-PRINTABLE");
+    }
+    lines
+}
+
+/// Splits `GROUP.NAME;PARAM=X;PARAM=Y:value` into the upper-cased name, the parameters and the
+/// value.
+fn split_property(line: &str) -> Option<(String, Vec<&str>, &str)> {
+    let (head, value) = line.split_once(':')?;
+    let mut params = head.split(';');
+    let name = params.next()?;
+    let name = name.rsplit('.').next().unwrap_or(name).to_ascii_uppercase();
+    Some((name, params.collect(), value))
+}
+
+/// Splits on `separator`, ignoring escaped ones, and unescapes each part.
+fn split_unescaped(value: &str, separator: char) -> Vec<String> {
+    let mut parts = vec![String::new()];
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(next) = chars.next() {
+                    let part = parts.last_mut().expect("never empty");
+                    part.push('\\');
+                    part.push(next);
+                }
+            }
+            c if c == separator => parts.push(String::new()),
+            c => parts.last_mut().expect("never empty").push(c),
+        }
+    }
+    parts
+        .iter()
+        .map(|part| unescape(part).trim().to_owned())
+        .collect()
+}
+
+fn unescape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => (),
+        }
+    }
+    out
+}
+
+fn decode_quoted_printable(value: &str) -> String {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut rest = value.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        rest = tail;
+        if byte != b'=' {
+            bytes.push(byte);
+            continue;
+        }
+        let hex = rest
+            .get(..2)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        if let Some(decoded) = hex {
+            bytes.push(decoded);
+            rest = &rest[2..];
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn only(text: &str) -> Contact {
+        let mut contacts = parse(text);
+        assert_eq!(contacts.len(), 1, "{contacts:?}");
+        contacts.remove(0)
+    }
+
+    #[test]
+    fn folded_lines() {
+        let contact = only(
+            "BEGIN:VCARD\r\nVERSION:3.0\r\nFN:Alice Mar\r\n garet Smith\r\nEMAIL:alice@\r\n\texample.com\r\nEND:VCARD\r\n",
+        );
+        assert_eq!(contact.name, "Alice Margaret Smith");
+        assert_eq!(contact.email, "alice@example.com");
+    }
+
+    #[test]
+    fn quoted_printable_soft_breaks() {
+        let contact = only(
+            "BEGIN:VCARD\nVERSION:2.1\nN;CHARSET=UTF-8;ENCODING=QUOTED-PRINTABLE:M=C3=BCller;J=\n=C3=BCrgen\nEND:VCARD\n",
+        );
+        assert_eq!(contact.family_name, "Müller");
+        assert_eq!(contact.name, "Jürgen Müller");
+
+        // 2.1 also allows the bare parameter
+        let contact = only("BEGIN:VCARD\nFN;QUOTED-PRINTABLE:Ren=C3=A9e\nEND:VCARD\n");
+        assert_eq!(contact.name, "Renée");
+    }
+
+    #[test]
+    fn escaped_separators() {
+        let contact = only(
+            "BEGIN:VCARD\nFN:Smith\\, Alice\nN:Smith\\;Jones;Alice;;;\nORG:Acme\\, Inc;Sales\nCATEGORIES:Family\\, close,Work\nEND:VCARD\n",
+        );
+        assert_eq!(contact.name, "Smith, Alice");
+        assert_eq!(contact.family_name, "Smith;Jones");
+        assert_eq!(contact.organization, "Acme, Inc");
+        assert_eq!(contact.categories, ["Family, close", "Work"]);
+    }
+
+    #[test]
+    fn grouped_properties() {
+        let contact = only(
+            "BEGIN:VCARD\nFN:Alice\nitem1.EMAIL;TYPE=INTERNET:alice@example.com\nitem1.X-ABLabel:home\nitem2.EMAIL:other@example.com\nEND:VCARD\n",
+        );
+        assert_eq!(contact.email, "alice@example.com");
+    }
+
+    #[test]
+    fn byte_order_mark() {
+        let text = "\u{feff}BEGIN:VCARD\nFN:Alice\nEND:VCARD\n";
+        assert!(is_vcard(text));
+        assert_eq!(only(text).name, "Alice");
+    }
+
+    #[test]
+    fn name_from_parts_and_nameless_cards() {
+        let contacts = parse(
+            "BEGIN:VCARD\nN:Smith;Alice;B.;Dr;\nEND:VCARD\nBEGIN:VCARD\nEMAIL:nobody@example.com\nEND:VCARD\n",
+        );
+        assert_eq!(contacts.len(), 1);
+        assert_eq!(contacts[0].name, "Alice B. Smith");
+    }
+}
@@ -1,7 +1,18 @@
 #![warn(clippy::all, rust_2018_idioms)]
 
+#[macro_use]
+mod i18n;
+
 mod app;
+mod avatar;
+mod calendar;
+mod fonts;
 mod hat;
+mod import;
+mod print;
+mod save;
+mod share;
+mod sound;
 pub use app::GiftExchangeApp;
 use hat::Person;
 
@@ -0,0 +1,229 @@
+//! A page of results and gift tags to print, since the side panel list doesn't print well.
+//!
+//! It's written as a standalone HTML file, so any browser can print it with the page breaks and
+//! cut lines laid out by its stylesheet.
+
+use std::fmt::Write;
+
+use crate::calendar::{Date, Event};
+use crate::hat::Pair;
+use crate::i18n;
+
+/// What goes on the page.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Copy, Debug)]
+#[serde(default)]
+pub(crate) struct Layout {
+    /// A table of who gives to whom, for the organizer.
+    pub(crate) results: bool,
+    /// A tag to cut out for each receiver's gift.
+    pub(crate) tags: bool,
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Self {
+            results: true,
+            tags: true,
+        }
+    }
+}
+
+const STYLE: &str = "
+body { font-family: sans-serif; margin: 2em; color: #222; }
+h1 { margin-bottom: 0; }
+.details { color: #555; margin-top: 0.3em; }
+table { border-collapse: collapse; margin: 1em 0; min-width: 60%; }
+th, td { border: 1px solid #999; padding: 0.4em 0.8em; text-align: start; }
+th { background: #eee; }
+.fixed { color: #777; font-style: italic; }
+.tags { display: flex; flex-wrap: wrap; gap: 0; }
+.tag { box-sizing: border-box; width: 33.3%; height: 5.5cm; padding: 1em; border: 1px dashed #999; display: flex; flex-direction: column; justify-content: center; align-items: center; text-align: center; break-inside: avoid; page-break-inside: avoid; }
+.tag .ornament { font-size: 2em; }
+.tag .to { font-size: 1.6em; font-weight: bold; margin: 0.3em 0; }
+.tag .from { font-style: italic; }
+.tags-page { break-before: page; page-break-before: always; }
+@media print {
+  body { margin: 0; }
+  .hint { display: none; }
+}
+";
+
+/// Builds the page for the pairs in `pairs`, headed with whatever is known about the `event`.
+pub(crate) fn html(event: &Event, pairs: &[&Pair], layout: Layout) -> String {
+    let title = if event.name.trim().is_empty() {
+        tr!("calendar-default-name")
+    } else {
+        event.name.trim()
+    };
+    let language = i18n::language();
+    let direction = if language.right_to_left() {
+        "rtl"
+    } else {
+        "ltr"
+    };
+
+    let mut out = String::new();
+    let _ = write!(
+        out,
+        "<!DOCTYPE html>\n<html lang=\"{}\" dir=\"{direction}\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>{STYLE}</style>\n</head>\n<body>\n",
+        language.code(),
+        escape(title),
+    );
+    let _ = writeln!(out, "<p class=\"hint\">{}</p>", escape(tr!("print-hint")));
+
+    if layout.results {
+        let _ = writeln!(out, "<h1>{}</h1>", escape(title));
+        let details: Vec<String> = [
+            Date::parse(&event.date).map(|date| date.to_string()),
+            Some(event.location.trim().to_owned()),
+            Some(event.notes.trim().to_owned()),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|detail| !detail.is_empty())
+        .collect();
+        if !details.is_empty() {
+            let _ = writeln!(
+                out,
+                "<p class=\"details\">{}</p>",
+                escape(&details.join(" · "))
+            );
+        }
+        let _ = writeln!(
+            out,
+            "<table>\n<tr><th>{}</th><th>{}</th></tr>",
+            escape(tr!("fixed-giver")),
+            escape(tr!("fixed-receiver")),
+        );
+        for pair in pairs {
+            let fixed = if pair.fixed {
+                format!(
+                    " <span class=\"fixed\">{}</span>",
+                    escape(tr!("wheel-fixed"))
+                )
+            } else {
+                String::new()
+            };
+            let _ = writeln!(
+                out,
+                "<tr><td>{}</td><td>{}{fixed}</td></tr>",
+                escape(&pair.giver.name),
+                escape(&pair.receiver.name),
+            );
+        }
+        out.push_str("</table>\n");
+    }
+
+    if layout.tags {
+        let class = if layout.results {
+            "tags tags-page"
+        } else {
+            "tags"
+        };
+        let _ = writeln!(out, "<div class=\"{class}\">");
+        for pair in pairs {
+            let _ = writeln!(
+                out,
+                "<div class=\"tag\"><div class=\"ornament\">🎁</div><div class=\"to\">{}</div><div class=\"from\">{}</div></div>",
+                escape(&tr!("print-tag-to", name = pair.receiver.name)),
+                escape(tr!("print-tag-from")),
+            );
+        }
+        out.push_str("</div>\n");
+    }
+
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hat::Person;
+
+    fn pairs() -> Vec<Pair> {
+        vec![
+            Pair::fixed(
+                Person::new("<b>Alice</b>", "Smith & \"Sons\""),
+                Person::new("Bob & Co", "<i>Jones</i>"),
+            ),
+            Pair {
+                giver: Person::new("Bob & Co", "<i>Jones</i>"),
+                receiver: Person::new("\"Carol\"", "Lee"),
+                fixed: false,
+            },
+            Pair {
+                giver: Person::new("\"Carol\"", "Lee"),
+                receiver: Person::new("<b>Alice</b>", "Smith & \"Sons\""),
+                fixed: false,
+            },
+        ]
+    }
+
+    fn page(layout: Layout) -> String {
+        let event = Event {
+            name: "Tom & Jerry's <party>".into(),
+            location: "\"The Barn\"".into(),
+            ..Event::default()
+        };
+        let pairs = pairs();
+        html(&event, &pairs.iter().collect::<Vec<_>>(), layout)
+    }
+
+    #[test]
+    fn escapes_names_and_details() {
+        let page = page(Layout::default());
+        for raw in ["<b>", "<i>", "Bob & Co", "\"Carol\"", "\"Sons\"", "<party>"] {
+            assert!(!page.contains(raw), "{raw} left in");
+        }
+        assert!(page.contains("&lt;b&gt;Alice&lt;/b&gt;"));
+        assert!(page.contains("Bob &amp; Co"));
+        assert!(page.contains("&quot;Carol&quot;"));
+        assert!(page.contains("<title>Tom &amp; Jerry&#39;s &lt;party&gt;</title>"));
+        assert!(page.contains("&quot;The Barn&quot;"));
+    }
+
+    #[test]
+    fn every_layout_prints_every_pair() {
+        let rows = |page: &str| page.matches("<tr><td>").count();
+        let tags = |page: &str| page.matches("<div class=\"tag\">").count();
+
+        let both = page(Layout::default());
+        assert_eq!((rows(&both), tags(&both)), (3, 3));
+        assert_eq!(both.matches(&escape(tr!("wheel-fixed"))).count(), 1);
+
+        let results = page(Layout {
+            results: true,
+            tags: false,
+        });
+        assert_eq!((rows(&results), tags(&results)), (3, 0));
+
+        let tags_only = page(Layout {
+            results: false,
+            tags: true,
+        });
+        assert_eq!((rows(&tags_only), tags(&tags_only)), (0, 3));
+        assert!(tags_only.contains("<div class=\"tags\">"));
+
+        for page in [both, results, tags_only] {
+            for pair in pairs() {
+                assert!(page.contains(&escape(&pair.receiver.name)));
+            }
+        }
+    }
+}
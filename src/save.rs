@@ -0,0 +1,38 @@
+//! Saving exported files: written to disk on native builds, downloaded by the browser on the web.
+
+/// Saves `contents` to `path`. On the web there's no file system, so only the file name is
+/// used, for the download.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn save(path: &str, contents: &str, _mime: &str) -> Result<(), String> {
+    std::fs::write(path, contents).map_err(|err| err.to_string())
+}
+
+/// Saves `contents` to `path`. On the web there's no file system, so only the file name is
+/// used, for the download.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn save(path: &str, contents: &str, mime: &str) -> Result<(), String> {
+    use wasm_bindgen::{JsCast, JsValue};
+
+    let js_error = |err: JsValue| err.as_string().unwrap_or_else(|| format!("{err:?}"));
+    let name = path.rsplit(['/', '\\']).next().unwrap_or(path);
+
+    let parts = js_sys::Array::of1(&JsValue::from_str(contents));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(mime);
+    let blob =
+        web_sys::Blob::new_with_str_sequence_and_options(&parts, &options).map_err(js_error)?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob).map_err(js_error)?;
+
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or_else(|| "no document".to_owned())?;
+    let link: web_sys::HtmlAnchorElement = document
+        .create_element("a")
+        .map_err(js_error)?
+        .dyn_into()
+        .map_err(|_| "not a link".to_owned())?;
+    link.set_href(&url);
+    link.set_download(name);
+    link.click();
+    web_sys::Url::revoke_object_url(&url).map_err(js_error)
+}
@@ -0,0 +1,265 @@
+//! Links that carry a whole setup, so an organizer can send the people list to someone else and
+//! they open the same setup in the web app.
+//!
+//! The setup goes in the URL fragment as `#setup=` followed by a small text format, compressed and
+//! base64 encoded. Fragments never reach the server, so the list stays between the two of them.
+//! Photos and email addresses are left out, photos being too big for a link and addresses being
+//! nobody else's business.
+
+use std::fmt::Write;
+
+use crate::avatar::Avatar;
+use crate::hat::{Pair, Person};
+
+const PREFIX: &str = "#setup=";
+
+/// Bumped if the format changes, so old links can still be read or at least turned away clearly.
+const VERSION: &str = "1";
+
+/// Anything longer than this once inflated didn't come from this app.
+const MAX_SIZE: usize = 1 << 20;
+
+/// People and fixed pairs, as read from a link.
+#[derive(Debug, Default)]
+pub(crate) struct Setup {
+    pub(crate) people: Vec<Person>,
+    pub(crate) fixed_pairs: Vec<Pair>,
+}
+
+/// The link to `base`, the address of the web app, that opens this setup.
+pub(crate) fn link(base: &str, people: &[Person], fixed_pairs: &[Pair]) -> String {
+    let mut text = format!("{VERSION}\n");
+    for person in people {
+        let _ = write!(text, "P\t{}\t{}", clean(&person.name), clean(&person.group));
+        if let Some(Avatar::Emoji(emoji)) = &person.avatar {
+            let _ = write!(text, "\t{}", clean(emoji));
+        }
+        text.push('\n');
+    }
+    for pair in fixed_pairs {
+        let giver = people.iter().position(|person| *person == pair.giver);
+        let receiver = people.iter().position(|person| *person == pair.receiver);
+        if let (Some(giver), Some(receiver)) = (giver, receiver) {
+            let _ = writeln!(text, "F\t{giver}\t{receiver}");
+        }
+    }
+
+    let compressed = miniz_oxide::deflate::compress_to_vec(text.as_bytes(), 9);
+    let encoded = base64::encode_config(compressed, base64::URL_SAFE_NO_PAD);
+    let base = base.split('#').next().unwrap_or(base);
+    format!("{base}{PREFIX}{encoded}")
+}
+
+/// Reads the setup from a URL fragment like `#setup=…`. Returns `None` if the fragment isn't a
+/// setup at all, such as `#dev`, and an error if it is one but can't be read.
+pub(crate) fn from_fragment(fragment: &str) -> Option<Result<Setup, String>> {
+    let encoded = fragment.strip_prefix(PREFIX)?;
+    Some(decode(encoded.trim()))
+}
+
+/// Reads the setup from a whole link, as pasted in by someone who was sent one.
+pub(crate) fn from_link(link: &str) -> Result<Setup, String> {
+    let fragment = link.find('#').map_or("", |idx| &link[idx..]);
+    from_fragment(fragment.trim()).unwrap_or_else(|| Err(tr!("share-bad-link").into()))
+}
+
+fn decode(encoded: &str) -> Result<Setup, String> {
+    let compressed = base64::decode_config(encoded, base64::URL_SAFE_NO_PAD)
+        .map_err(|_| tr!("share-bad-link").to_owned())?;
+    let bytes = miniz_oxide::inflate::decompress_to_vec_with_limit(&compressed, MAX_SIZE)
+        .map_err(|_| tr!("share-bad-link").to_owned())?;
+    let text = String::from_utf8(bytes).map_err(|_| tr!("share-bad-link").to_owned())?;
+
+    let mut lines = text.lines();
+    match lines.next() {
+        Some(VERSION) => (),
+        Some(version) if version.parse::<u32>().is_ok() => {
+            return Err(tr!("share-newer-version").into())
+        }
+        _ => return Err(tr!("share-bad-link").into()),
+    }
+
+    let mut setup = Setup::default();
+    for (idx, line) in lines.enumerate() {
+        // the version was line 1
+        let number = idx + 2;
+        let fields: Vec<_> = line.split('\t').collect();
+        match fields[..] {
+            ["P", name, group, ..] => {
+                // people can be added without a group, so only the name is needed
+                let (name, group) = (name.trim(), group.trim());
+                if name.is_empty() {
+                    return Err(tr!("share-bad-line", line = number));
+                }
+                let mut person = Person::new(name, group);
+                if setup.people.contains(&person) {
+                    return Err(tr!("import-duplicate", name = name, group = group));
+                }
+                if let Some(emoji) = fields.get(3).filter(|emoji| !emoji.is_empty()) {
+                    person.avatar = Some(Avatar::Emoji((*emoji).to_owned()));
+                }
+                setup.people.push(person);
+            }
+            ["F", giver, receiver] => {
+                let person = |index: &str| {
+                    index
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|index| setup.people.get(index))
+                        .cloned()
+                        .ok_or_else(|| tr!("share-bad-line", line = number))
+                };
+                let pair = Pair::fixed(person(giver)?, person(receiver)?);
+                if pair.giver == pair.receiver {
+                    return Err(tr!("fixed-self").into());
+                }
+                if setup.fixed_pairs.iter().any(|p| p.giver == pair.giver) {
+                    return Err(tr!("fixed-already-giving", name = pair.giver.name));
+                }
+                if setup
+                    .fixed_pairs
+                    .iter()
+                    .any(|p| p.receiver == pair.receiver)
+                {
+                    return Err(tr!("fixed-already-receiving", name = pair.receiver.name));
+                }
+                setup.fixed_pairs.push(pair);
+            }
+            [""] => (),
+            _ => return Err(tr!("share-bad-line", line = number)),
+        }
+    }
+    Ok(setup)
+}
+
+/// Keeps a field from breaking the line-and-tab format.
+fn clean(text: &str) -> String {
+    text.replace(['\t', '\n', '\r'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fragment holding `text` as a link would.
+    fn fragment(text: &[u8]) -> String {
+        let compressed = miniz_oxide::deflate::compress_to_vec(text, 9);
+        format!(
+            "{PREFIX}{}",
+            base64::encode_config(compressed, base64::URL_SAFE_NO_PAD)
+        )
+    }
+
+    fn error(text: &[u8]) -> String {
+        from_fragment(&fragment(text))
+            .expect("is a setup fragment")
+            .expect_err("should be turned away")
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut alice = Person::new("Alice", "Smith, Jr");
+        alice.avatar = Some(Avatar::Emoji("🎅".into()));
+        alice.email = "alice@example.com".into();
+        let bob = Person::new("Bob", "Jones");
+        let people = vec![alice.clone(), bob.clone(), Person::new("Carol", "Lee")];
+        let fixed_pairs = vec![Pair::fixed(alice, bob)];
+
+        let link = link("https://example.com/app/#dev", &people, &fixed_pairs);
+        assert!(link.starts_with("https://example.com/app/#setup="));
+        let setup = from_link(&link).unwrap();
+        assert_eq!(setup.people, people);
+        assert_eq!(setup.people[0].avatar, people[0].avatar);
+        assert_eq!(setup.people[0].email, "");
+        assert_eq!(setup.fixed_pairs, fixed_pairs);
+    }
+
+    #[test]
+    fn other_fragments_are_not_setups() {
+        assert!(from_fragment("#dev").is_none());
+        assert!(from_fragment("").is_none());
+        assert_eq!(
+            from_link("https://example.com/").unwrap_err(),
+            tr!("share-bad-link")
+        );
+    }
+
+    #[test]
+    fn bad_base64() {
+        let err = from_fragment("#setup=not*base64!").unwrap().unwrap_err();
+        assert_eq!(err, tr!("share-bad-link"));
+    }
+
+    #[test]
+    fn too_big_once_inflated() {
+        let mut text = b"1\n".to_vec();
+        text.resize(MAX_SIZE + 1, b'x');
+        assert_eq!(error(&text), tr!("share-bad-link"));
+    }
+
+    #[test]
+    fn newer_version() {
+        assert_eq!(error(b"2\nP\tAlice\tSmith\n"), tr!("share-newer-version"));
+    }
+
+    #[test]
+    fn person_without_group() {
+        let setup = from_fragment(&fragment(b"1\nP\tAlice\t\n"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(setup.people, [Person::new("Alice", "")]);
+        // the group's field is always written, even when empty
+        assert_eq!(error(b"1\nP\tAlice\n"), tr!("share-bad-line", line = 2));
+    }
+
+    #[test]
+    fn person_without_name() {
+        assert_eq!(error(b"1\nP\t\tSmith\n"), tr!("share-bad-line", line = 2));
+    }
+
+    #[test]
+    fn round_trip_anyone_the_people_page_can_add() {
+        let mut people = vec![
+            Person::new("Alice", "Smith, Jr"),
+            Person::new("Bob", ""),
+            Person::new("José 李", "Müller: #1 100%"),
+            Person::new("🎅", "🎄"),
+            Person::new("Alice", ""),
+        ];
+        people[1].avatar = Some(Avatar::Emoji("🦊".into()));
+        people[2].avatar = Some(Avatar::Photo {
+            id: 7,
+            legacy_png: Vec::new(),
+        });
+        let fixed_pairs = vec![
+            Pair::fixed(people[1].clone(), people[3].clone()),
+            Pair::fixed(people[4].clone(), people[0].clone()),
+        ];
+
+        let setup = from_link(&link("https://example.com/", &people, &fixed_pairs)).unwrap();
+        assert_eq!(setup.people, people);
+        assert_eq!(setup.fixed_pairs, fixed_pairs);
+        // photos are left out, emoji kept
+        let avatars: Vec<_> = setup.people.iter().map(|p| p.avatar.clone()).collect();
+        assert_eq!(
+            avatars,
+            [None, Some(Avatar::Emoji("🦊".into())), None, None, None]
+        );
+    }
+
+    #[test]
+    fn fixed_pair_out_of_range() {
+        assert_eq!(
+            error(b"1\nP\tAlice\tSmith\nP\tBob\tJones\nF\t0\t2\n"),
+            tr!("share-bad-line", line = 4)
+        );
+    }
+
+    #[test]
+    fn duplicate_giver() {
+        assert_eq!(
+            error(b"1\nP\tAlice\tSmith\nP\tBob\tJones\nP\tCarol\tLee\nF\t0\t1\nF\t0\t2\n"),
+            tr!("fixed-already-giving", name = "Alice")
+        );
+    }
+}
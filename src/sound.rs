@@ -0,0 +1,205 @@
+//! Sound effects for the wheel.
+//!
+//! The effects are synthesized once on first use rather than shipped as audio files, and played
+//! through rodio on native builds (with the `sound` feature) or the Web Audio API on the web.
+
+use std::f32::consts::TAU;
+
+const SAMPLE_RATE: u32 = 44_100;
+
+/// Native builds only have sound with the `sound` feature, which needs ALSA on Linux.
+const BUILT_WITH_SOUND: bool = cfg!(any(target_arch = "wasm32", feature = "sound"));
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Effect {
+    /// A wedge boundary passed the pointer.
+    Tick,
+    /// The wheel is winding up.
+    Whoosh,
+    /// The wheel stopped on someone.
+    Fanfare,
+}
+
+/// Plays sound effects, opening the audio device the first time one is needed.
+#[derive(Default)]
+pub(crate) struct Sound {
+    backend: Option<backend::Backend>,
+    /// Set once opening the audio device has failed so it isn't retried every frame.
+    unavailable: bool,
+    samples: Vec<(Effect, Vec<f32>)>,
+}
+
+impl Sound {
+    pub(crate) fn play(&mut self, effect: Effect) {
+        if !self.available() {
+            return;
+        }
+        let Some(backend) = &self.backend else { return };
+
+        if !self.samples.iter().any(|(e, _)| *e == effect) {
+            self.samples.push((effect, synthesize(effect)));
+        }
+        if let Some((_, samples)) = self.samples.iter().find(|(e, _)| *e == effect) {
+            backend.play(samples);
+        }
+    }
+
+    /// Why effects can't be heard, if they can't. Only knows about the audio device once it has
+    /// been tried for the first effect, so showing this never opens it.
+    pub(crate) fn unavailable_reason(&self) -> Option<&'static str> {
+        if !BUILT_WITH_SOUND {
+            Some(tr!("settings-sound-not-built"))
+        } else if self.unavailable {
+            Some(tr!("settings-sound-no-device"))
+        } else {
+            None
+        }
+    }
+
+    /// Opens the audio device the first time it's needed, remembering if that failed.
+    fn available(&mut self) -> bool {
+        if self.backend.is_none() && !self.unavailable {
+            self.backend = backend::Backend::new();
+            self.unavailable = self.backend.is_none();
+        }
+        !self.unavailable
+    }
+}
+
+fn synthesize(effect: Effect) -> Vec<f32> {
+    match effect {
+        Effect::Tick => tone(0.03, |t| {
+            let envelope = (-t * 200.0).exp();
+            envelope * 0.4 * (TAU * 2_000.0 * t).sin()
+        }),
+        Effect::Whoosh => {
+            // low-passed noise with a cutoff that sweeps up then back down
+            let mut noise = Noise(0x2545_f491);
+            let mut filtered = 0.0;
+            let duration = 0.8;
+            tone(duration, move |t| {
+                let progress = t / duration;
+                let envelope = (progress * std::f32::consts::PI).sin();
+                let smoothing = 0.02 + 0.2 * envelope;
+                filtered += smoothing * (noise.next() - filtered);
+                envelope * 0.5 * filtered
+            })
+        }
+        Effect::Fanfare => {
+            // C5, E5, G5 then a held C6
+            let notes = [
+                (523.25, 0.12),
+                (659.25, 0.12),
+                (783.99, 0.12),
+                (1_046.5, 0.5),
+            ];
+            notes
+                .iter()
+                .flat_map(|&(frequency, duration)| {
+                    tone(duration, move |t| {
+                        let envelope = (1.0 - t / duration).min(t * 100.0).min(1.0);
+                        let wave = (TAU * frequency * t).sin()
+                            + 0.3 * (TAU * 2.0 * frequency * t).sin()
+                            + 0.1 * (TAU * 3.0 * frequency * t).sin();
+                        envelope * 0.25 * wave
+                    })
+                })
+                .collect()
+        }
+    }
+}
+
+/// Samples `duration` seconds of `f`, which is given the time in seconds.
+fn tone(duration: f32, mut f: impl FnMut(f32) -> f32) -> Vec<f32> {
+    let len = (duration * SAMPLE_RATE as f32) as usize;
+    (0..len).map(|i| f(i as f32 / SAMPLE_RATE as f32)).collect()
+}
+
+/// A tiny xorshift generator, so the synthesized sounds are the same every time.
+struct Noise(u32);
+
+impl Noise {
+    fn next(&mut self) -> f32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        self.0 as f32 / u32::MAX as f32 * 2.0 - 1.0
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod backend {
+    pub(super) struct Backend {
+        context: web_sys::AudioContext,
+    }
+
+    impl Backend {
+        pub(super) fn new() -> Option<Self> {
+            let context = web_sys::AudioContext::new().ok()?;
+            Some(Self { context })
+        }
+
+        pub(super) fn play(&self, samples: &[f32]) {
+            // browsers start the context suspended until the page has been interacted with
+            let _ = self.context.resume();
+
+            let Ok(buffer) =
+                self.context
+                    .create_buffer(1, samples.len() as u32, super::SAMPLE_RATE as f32)
+            else {
+                return;
+            };
+            if buffer.copy_to_channel(samples, 0).is_err() {
+                return;
+            }
+            let Ok(source) = self.context.create_buffer_source() else {
+                return;
+            };
+            source.set_buffer(Some(&buffer));
+            if source
+                .connect_with_audio_node(&self.context.destination())
+                .is_ok()
+            {
+                let _ = source.start();
+            }
+        }
+    }
+}
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "sound"))]
+mod backend {
+    pub(super) struct Backend {
+        // dropping the stream stops all sound, so it is kept alongside the handle
+        _stream: rodio::OutputStream,
+        handle: rodio::OutputStreamHandle,
+    }
+
+    impl Backend {
+        pub(super) fn new() -> Option<Self> {
+            let (stream, handle) = rodio::OutputStream::try_default().ok()?;
+            Some(Self {
+                _stream: stream,
+                handle,
+            })
+        }
+
+        pub(super) fn play(&self, samples: &[f32]) {
+            let source = rodio::buffer::SamplesBuffer::new(1, super::SAMPLE_RATE, samples);
+            let _ = self.handle.play_raw(source);
+        }
+    }
+}
+
+#[cfg(all(not(target_arch = "wasm32"), not(feature = "sound")))]
+mod backend {
+    /// Built without the `sound` feature, so there is nothing to play through.
+    pub(super) struct Backend;
+
+    impl Backend {
+        pub(super) fn new() -> Option<Self> {
+            None
+        }
+
+        pub(super) fn play(&self, _samples: &[f32]) {}
+    }
+}